@@ -0,0 +1,41 @@
+//! Micro-benchmark of the branch-free `Float::abs` against `Signed::abs`, which multiplies by the
+//! `signum` of the real part, over a slice with signs that change at random.
+//!
+//! Run with `cargo bench --bench abs`.
+
+extern crate dual_num;
+extern crate num_traits;
+
+use std::hint::black_box;
+
+use dual_num::{DualNumber, Float};
+use num_traits::Signed;
+
+mod common;
+
+const LEN: usize = 4096;
+const ROUNDS: usize = 2000;
+
+/// Maps `f` over the inputs into `out` in each round.
+fn time<F>(name: &str, xs: &[DualNumber<f64>], out: &mut [DualNumber<f64>], f: F)
+    where F: Fn(DualNumber<f64>) -> DualNumber<f64> {
+    common::time_rounds(name, LEN, ROUNDS, |_| black_box(xs), |xs| {
+        for (o, &x) in out.iter_mut().zip(*xs) {
+            *o = f(x);
+        }
+        black_box(&mut *out);
+    });
+}
+
+fn main() {
+    // A linear congruential generator, so that the signs are not predictable
+    let mut state = 12345u64;
+    let xs: Vec<_> = (0..LEN).map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        DualNumber::new((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5, 1.0)
+    }).collect();
+    let mut out = vec![DualNumber::new(0.0, 0.0); LEN];
+
+    time("abs select", &xs, &mut out, Float::abs);
+    time("abs signum", &xs, &mut out, |x| Signed::abs(&x));
+}
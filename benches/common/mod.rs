@@ -0,0 +1,42 @@
+//! Timing harness shared by the micro-benchmarks
+//!
+//! Each benchmark keeps the fastest of many rounds, which is the least disturbed by the rest of the
+//! system, and prints it per element of the batch.
+
+// Not every benchmark uses every helper
+#![allow(dead_code)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use dual_num::DualNumber;
+
+/// Times `run` on the output of `setup` for each of `rounds` rounds, and prints the fastest round
+/// per element of a batch of `len`.
+///
+/// `setup` gets the index of the round and is not timed, nor is dropping its output.
+pub fn time_rounds<P, S, F>(name: &str, len: usize, rounds: usize, mut setup: S, mut run: F)
+    where S: FnMut(usize) -> P, F: FnMut(&mut P) {
+    let mut best = Duration::from_secs(u64::MAX);
+
+    for round in 0..rounds {
+        let mut input = setup(round);
+        let start = Instant::now();
+
+        run(&mut input);
+
+        best = best.min(start.elapsed());
+    }
+
+    println!("{:<24} {:>8.3} ns/op", name, best.as_secs_f64() * 1e9 / len as f64);
+}
+
+/// Times `f` applied to each of `xs` for each of `rounds` rounds, and prints the fastest round.
+pub fn time_each<T: Copy, F>(name: &str, xs: &[DualNumber<T>], rounds: usize, f: F)
+    where F: Fn(DualNumber<T>) -> DualNumber<T> {
+    time_rounds(name, xs.len(), rounds, |_| (), |_| {
+        for &x in xs {
+            black_box(f(black_box(x)));
+        }
+    });
+}
@@ -0,0 +1,71 @@
+//! Micro-benchmark of batch operations on a `DualBuffer` against the same loops over a
+//! `Vec<DualNumber<f64>>`.
+//!
+//! Run with `cargo bench --bench dual_buffer`.
+
+extern crate dual_num;
+
+use std::hint::black_box;
+
+use dual_num::{DualBuffer, DualNumber, Float};
+
+mod common;
+
+const LEN: usize = 1 << 16;
+const ROUNDS: usize = 200;
+
+/// Times `f` on a fresh copy of `input` in each round.
+fn time<B: Clone, F>(name: &str, input: &B, f: F) where F: Fn(&mut B) {
+    common::time_rounds(name, LEN, ROUNDS, |_| input.clone(), |data| {
+        f(data);
+        black_box(&*data);
+    });
+}
+
+fn main() {
+    let xs: Vec<_> = (0..LEN).map(|i| DualNumber::new(1.0 + i as f64 * 1e-4, 0.5 - i as f64 * 1e-6)).collect();
+    let ys: Vec<_> = xs.iter().map(|&x| x * 0.75 + 0.5).collect();
+    let (xb, yb) = (DualBuffer::from(&xs[..]), DualBuffer::from(&ys[..]));
+
+    time("vec mul", &xs, |v| {
+        for (x, &y) in v.iter_mut().zip(&ys) {
+            *x = *x * y;
+        }
+    });
+    time("buffer mul_assign", &xb, |b| b.mul_assign(&yb));
+
+    time("vec sqrt", &xs, |v| {
+        for x in v.iter_mut() {
+            *x = x.sqrt();
+        }
+    });
+    time("buffer sqrt", &xb, |b| b.sqrt());
+
+    time("vec exp", &xs, |v| {
+        for x in v.iter_mut() {
+            *x = x.exp();
+        }
+    });
+    time("buffer exp", &xb, |b| b.exp());
+
+    time("vec ln", &xs, |v| {
+        for x in v.iter_mut() {
+            *x = x.ln();
+        }
+    });
+    time("buffer ln", &xb, |b| b.ln());
+
+    time("vec sin", &xs, |v| {
+        for x in v.iter_mut() {
+            *x = x.sin();
+        }
+    });
+    time("buffer sin", &xb, |b| b.sin());
+
+    time("vec tanh", &xs, |v| {
+        for x in v.iter_mut() {
+            *x = x.tanh();
+        }
+    });
+    time("buffer tanh", &xb, |b| b.tanh());
+}
@@ -0,0 +1,31 @@
+//! Micro-benchmark of the fast approximations against the precise functions, for `f64`, and of
+//! `fast_tanh` for `f32`, whose other methods return the precise functions.
+//!
+//! Run with `cargo bench --bench fast_math`.
+
+extern crate dual_num;
+
+use dual_num::{DualNumber, Float};
+
+mod common;
+
+const LEN: usize = 4096;
+const ROUNDS: usize = 500;
+
+fn main() {
+    let xs: Vec<_> = (0..LEN).map(|i| DualNumber::new(i as f64 / LEN as f64 * 20.0 - 10.0, 1.0)).collect();
+
+    common::time_each("exp", &xs, ROUNDS, Float::exp);
+    common::time_each("fast_exp", &xs, ROUNDS, DualNumber::<f64>::fast_exp);
+    common::time_each("sin", &xs, ROUNDS, Float::sin);
+    common::time_each("fast_sin", &xs, ROUNDS, DualNumber::<f64>::fast_sin);
+    common::time_each("cos", &xs, ROUNDS, Float::cos);
+    common::time_each("fast_cos", &xs, ROUNDS, DualNumber::<f64>::fast_cos);
+    common::time_each("tanh", &xs, ROUNDS, Float::tanh);
+    common::time_each("fast_tanh", &xs, ROUNDS, DualNumber::<f64>::fast_tanh);
+
+    let xs: Vec<_> = xs.iter().map(|x| DualNumber::new(x.real() as f32, 1.0)).collect();
+
+    common::time_each("tanh f32", &xs, ROUNDS, Float::tanh);
+    common::time_each("fast_tanh f32", &xs, ROUNDS, DualNumber::<f32>::fast_tanh);
+}
@@ -0,0 +1,33 @@
+//! Micro-benchmark of the inverse trigonometric and hyperbolic derivatives, against the same
+//! formulas written with `powi(2)`.
+//!
+//! Where LLVM lowers `powi(x, 2)` to a multiply, as on x86_64, both versions run at the same speed.
+//! The difference shows on targets where `powi` is a library call.
+//!
+//! Run with `cargo bench --bench inverse_trig`.
+
+extern crate dual_num;
+
+use dual_num::{DualNumber, Float};
+
+mod common;
+
+const LEN: usize = 4096;
+const ROUNDS: usize = 500;
+
+fn main() {
+    let xs: Vec<_> = (0..LEN).map(|i| DualNumber::new(i as f64 / LEN as f64 * 1.8 - 0.9, 1.0)).collect();
+
+    common::time_each("asin", &xs, ROUNDS, |x| x.asin());
+    common::time_each("asin powi", &xs, ROUNDS, |x| {
+        DualNumber::new(x.real().asin(), x.dual() / (1.0 - x.real().powi(2)).sqrt())
+    });
+    common::time_each("atanh", &xs, ROUNDS, |x| x.atanh());
+    common::time_each("atanh powi", &xs, ROUNDS, |x| DualNumber::new(x.real().atanh(), x.dual() / (1.0 - x.real().powi(2))));
+    common::time_each("atan2", &xs, ROUNDS, |x| x.atan2(x + 0.5));
+    common::time_each("atan2 powi", &xs, ROUNDS, |x| {
+        let (y, z) = (x, x + 0.5);
+        DualNumber::new(y.real().atan2(z.real()),
+                        (z.real() * y.dual() - y.real() * z.dual()) / (y.real().powi(2) + z.real().powi(2)))
+    });
+}
@@ -0,0 +1,24 @@
+//! Micro-benchmark of `powf` with a constant exponent, which skips the `ln` of the base, against
+//! an exponent with a non-zero dual part, which evaluates it.
+//!
+//! Run with `cargo bench --bench powf`.
+
+extern crate dual_num;
+
+use std::hint::black_box;
+
+use dual_num::{DualNumber, Float};
+
+mod common;
+
+const LEN: usize = 4096;
+const ROUNDS: usize = 500;
+
+fn main() {
+    let xs: Vec<_> = (0..LEN).map(|i| DualNumber::new(0.01 + i as f64 * 0.37, 1.0)).collect();
+    let constant = black_box(DualNumber::from_real(2.7));
+    let seeded = black_box(DualNumber::new(2.7, 1.0));
+
+    common::time_each("powf constant", &xs, ROUNDS, |x| x.powf(constant));
+    common::time_each("powf seeded", &xs, ROUNDS, |x| x.powf(seeded));
+}
@@ -0,0 +1,29 @@
+//! Micro-benchmark of `powi` for small exponents, which are unrolled into dual multiplications,
+//! against the general formula `(x^n, n x^(n-1) x')` with two float `powi` calls.
+//!
+//! Run with `cargo bench --bench powi`.
+
+extern crate dual_num;
+
+use std::hint::black_box;
+
+use dual_num::{DualNumber, Float};
+
+mod common;
+
+const LEN: usize = 4096;
+const ROUNDS: usize = 2000;
+
+/// The general formula, as `powi` computes it for larger exponents.
+fn general(x: DualNumber<f64>, n: i32) -> DualNumber<f64> {
+    DualNumber::new(x.real().powi(n), n as f64 * x.real().powi(n - 1) * x.dual())
+}
+
+fn main() {
+    let xs: Vec<_> = (0..LEN).map(|i| DualNumber::new(i as f64 * 0.37 - 500.0, 1.0)).collect();
+
+    for n in 2..4 {
+        common::time_each(&format!("powi {}", n), &xs, ROUNDS, |x| x.powi(black_box(n)));
+        common::time_each(&format!("powi {} general", n), &xs, ROUNDS, |x| general(x, black_box(n)));
+    }
+}
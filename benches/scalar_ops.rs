@@ -0,0 +1,35 @@
+//! Micro-benchmark of scaling by a constant and of `recip`, against the equivalent products and
+//! quotients with constant dual numbers.
+//!
+//! Run with `cargo bench --bench scalar_ops`.
+
+extern crate dual_num;
+
+use std::hint::black_box;
+
+use dual_num::{DualNumber, Float, One};
+
+mod common;
+
+const LEN: usize = 4096;
+const ROUNDS: usize = 2000;
+
+/// Times `f` over the inputs with a constant that changes between rounds.
+fn time<F>(name: &str, xs: &[DualNumber<f64>], f: F) where F: Fn(DualNumber<f64>, f64) -> DualNumber<f64> {
+    common::time_rounds(name, xs.len(), ROUNDS, |round| black_box(1.0 + round as f64 * 1e-3), |&mut c| {
+        for &x in xs {
+            black_box(f(black_box(x), c));
+        }
+    });
+}
+
+fn main() {
+    let xs: Vec<_> = (0..LEN).map(|i| DualNumber::new(i as f64 * 0.37 - 500.0, 1.0 / (i as f64 + 1.0))).collect();
+
+    time("mul scalar", &xs, |x, c| x * c);
+    time("mul from_real", &xs, |x, c| x * DualNumber::from_real(c));
+    time("div scalar", &xs, |x, c| x / c);
+    time("div from_real", &xs, |x, c| x / DualNumber::from_real(c));
+    time("recip", &xs, |x, _| x.recip());
+    time("one / x", &xs, |x, _| DualNumber::<f64>::one() / x);
+}
@@ -0,0 +1,158 @@
+//! Functions which lose precision to cancellation when written naively
+//!
+//! Like `exp_m1` and `ln_1p`, these are evaluated so that both parts keep full
+//! relative accuracy for small arguments.
+
+use super::{DualNumber, Float, FloatConst, polyval};
+
+use num_traits::Signed;
+
+/// Taylor coefficients of `exprel`, `1 / (k + 1)!` in ascending order
+const EXPREL: [f64; 18] = [1.0, 1.0 / 2.0, 1.0 / 6.0, 1.0 / 24.0, 1.0 / 120.0, 1.0 / 720.0, 1.0 / 5_040.0,
+    1.0 / 40_320.0, 1.0 / 362_880.0, 1.0 / 3_628_800.0, 1.0 / 39_916_800.0, 1.0 / 479_001_600.0, 1.0 / 6_227_020_800.0,
+    1.0 / 87_178_291_200.0, 1.0 / 1_307_674_368_000.0, 1.0 / 20_922_789_888_000.0, 1.0 / 355_687_428_096_000.0,
+    1.0 / 6_402_373_705_728_000.0];
+
+/// Taylor coefficients of `sinhc`, `1 / (k + 1)!` for even `k` and zero for odd `k`, in ascending order
+const SINHC: [f64; 17] = [1.0, 0.0, 1.0 / 6.0, 0.0, 1.0 / 120.0, 0.0, 1.0 / 5_040.0, 0.0, 1.0 / 362_880.0, 0.0,
+    1.0 / 39_916_800.0, 0.0, 1.0 / 6_227_020_800.0, 0.0, 1.0 / 1_307_674_368_000.0, 0.0, 1.0 / 355_687_428_096_000.0];
+
+impl<T> DualNumber<T> where T: Float + Signed + FloatConst {
+    /// Computes `cos(x) - 1`, with derivative `-sin(x)`.
+    ///
+    /// The value is evaluated as `-2 sin²(x/2)`, avoiding the cancellation in `cos(x) - 1` for small `x`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for &x in &[1e-8f64, 1e-12] {
+    ///     let c = DualNumber::new(x, 1.0).cos_m1();
+    ///     let reference = -x * x / 2.0 + x.powi(4) / 24.0;
+    ///
+    ///     assert!(((c.real() - reference) / reference).abs() < 1e-15);
+    ///     assert!(((c.dual() + x) / x).abs() < 1e-15);
+    /// }
+    ///
+    /// let naive = DualNumber::new(1.0f64, 1.0).cos() - 1.0;
+    /// let c = DualNumber::new(1.0f64, 1.0).cos_m1();
+    /// assert!((c.real() - naive.real()).abs() < 1e-15);
+    /// assert!((c.dual() - naive.dual()).abs() < 1e-15);
+    /// ```
+    pub fn cos_m1(self) -> Self {
+        let x = self.real();
+        let s = (x / T::from(2).unwrap()).sin();
+
+        DualNumber::new(-(s * s + s * s), -self.dual() * x.sin())
+    }
+
+    /// Computes `sqrt(1 + x) - 1`, with derivative `1 / (2 sqrt(1 + x))`.
+    ///
+    /// The value is evaluated as `x / (sqrt(1 + x) + 1)`, avoiding the cancellation for small `x`.
+    /// Both parts are NaN for `x < -1`, and the derivative is infinite at `x = -1`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for &x in &[1e-8f64, 1e-12, -1e-8] {
+    ///     let c = DualNumber::new(x, 1.0).sqrt_1p_m1();
+    ///     let reference = x / 2.0 - x * x / 8.0;
+    ///
+    ///     assert!(((c.real() - reference) / reference).abs() < 1e-15);
+    ///     assert!((c.dual() - (0.5 - x / 4.0)).abs() < 1e-15);
+    /// }
+    ///
+    /// let naive = DualNumber::new(2.0f64, 1.0).sqrt() - 1.0;
+    /// let c = DualNumber::new(1.0f64, 1.0).sqrt_1p_m1();
+    /// assert!((c.real() - naive.real()).abs() < 1e-15);
+    /// assert!((c.dual() - naive.dual()).abs() < 1e-15);
+    ///
+    /// assert_eq!(DualNumber::new(-1.0f64, 1.0).sqrt_1p_m1().into_tuple(), (-1.0, f64::INFINITY));
+    /// assert!(DualNumber::new(-2.0f64, 1.0).sqrt_1p_m1().real().is_nan());
+    /// ```
+    pub fn sqrt_1p_m1(self) -> Self {
+        let x = self.real();
+        let root = (T::one() + x).sqrt();
+
+        DualNumber::new(x / (root + T::one()), self.dual() / (root + root))
+    }
+
+    /// Computes the relative exponential `exprel(x) = (e^x - 1) / x`, with `exprel(0) = 1`.
+    ///
+    /// Near zero both parts are evaluated from the Taylor series, giving `exprel(0) = 1` with a derivative
+    /// of exactly `1/2`, and the direct formulas are used for `|x| >= 1/2`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).exprel().into_tuple(), (1.0, 0.5));
+    ///
+    /// for &x in &[1e-9f64, -1e-9] {
+    ///     let e = DualNumber::new(x, 1.0).exprel();
+    ///     assert!((e.real() - (1.0 + x / 2.0)).abs() < 1e-16);
+    ///     assert!((e.dual() - (0.5 + x / 3.0)).abs() < 1e-16);
+    /// }
+    ///
+    /// // Continuity across the threshold
+    /// let (lo, hi) = (DualNumber::new(0.5 - 1e-12, 1.0).exprel(), DualNumber::new(0.5f64, 1.0).exprel());
+    /// assert!((lo.real() - hi.real()).abs() < 1e-11);
+    /// assert!((lo.dual() - hi.dual()).abs() < 1e-11);
+    ///
+    /// // Direct formula and finite differences away from zero
+    /// let x = DualNumber::new(3.0f64, 1.0);
+    /// assert!((x.exprel().real() - x.exp_m1().real() / 3.0).abs() < 1e-15);
+    ///
+    /// let h = 1e-6;
+    /// let fd = ((3.0f64 + h).exp_m1() / (3.0 + h) - (3.0f64 - h).exp_m1() / (3.0 - h)) / (2.0 * h);
+    /// assert!((x.exprel().dual() - fd).abs() < 1e-8);
+    /// ```
+    pub fn exprel(self) -> Self {
+        let x = self.real();
+
+        if x.abs() < T::from(0.5).unwrap() {
+            // Σ x^k / (k + 1)!, which also differentiates the series exactly
+            return polyval(&EXPREL.map(|c| T::from(c).unwrap()), self);
+        }
+
+        let value = x.exp_m1() / x;
+
+        DualNumber::new(value, self.dual() * (x.exp() - value) / x)
+    }
+
+    /// Computes `sinhc(x) = sinh(x) / x`, with `sinhc(0) = 1`.
+    ///
+    /// Near zero both parts are evaluated from the Taylor series, giving `sinhc(0) = 1` with a derivative
+    /// of exactly zero, and the direct formulas are used for `|x| >= 1/2`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).sinhc().into_tuple(), (1.0, 0.0));
+    ///
+    /// for &x in &[1e-9f64, -1e-9] {
+    ///     let s = DualNumber::new(x, 1.0).sinhc();
+    ///     assert_eq!(s.real(), 1.0);
+    ///     assert!((s.dual() - x / 3.0).abs() < 1e-24);
+    /// }
+    ///
+    /// // Continuity across the threshold
+    /// let (lo, hi) = (DualNumber::new(0.5 - 1e-12, 1.0).sinhc(), DualNumber::new(0.5f64, 1.0).sinhc());
+    /// assert!((lo.real() - hi.real()).abs() < 1e-11);
+    /// assert!((lo.dual() - hi.dual()).abs() < 1e-11);
+    ///
+    /// // Direct formula and finite differences away from zero
+    /// let x = DualNumber::new(-3.0f64, 1.0);
+    /// assert!((x.sinhc().real() - 3f64.sinh() / 3.0).abs() < 1e-15);
+    ///
+    /// let h = 1e-6;
+    /// let fd = ((-3.0f64 + h).sinh() / (-3.0 + h) - (-3.0f64 - h).sinh() / (-3.0 - h)) / (2.0 * h);
+    /// assert!((x.sinhc().dual() - fd).abs() < 1e-8);
+    /// ```
+    pub fn sinhc(self) -> Self {
+        let x = self.real();
+
+        if x.abs() < T::from(0.5).unwrap() {
+            // Σ x^2k / (2k + 1)!, as a polynomial in x with zero odd coefficients
+            return polyval(&SINHC.map(|c| T::from(c).unwrap()), self);
+        }
+
+        let value = x.sinh() / x;
+
+        DualNumber::new(value, self.dual() * (x.cosh() - value) / x)
+    }
+}
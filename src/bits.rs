@@ -0,0 +1,111 @@
+//! Bit-exact binary encoding of `f32` and `f64` dual numbers
+//!
+//! The encoding packs the bits of the real part in the high half of an integer twice the width
+//! of the parts, and those of the dual part in the low half. The byte arrays are that integer in
+//! little-endian order, so the dual part's bytes come first. Every bit pattern round trips,
+//! including `-0.0`, infinities and NaN payloads.
+
+use super::DualNumber;
+
+macro_rules! impl_bits {
+    ($(#[$attr:meta])* $t:ident, $half:ident, $bits:ident, $bytes:expr) => {
+        $(#[$attr])*
+        impl DualNumber<$t> {
+            /// Returns the bits of the real part in the high half and those of the dual part in the low half.
+            #[inline]
+            pub fn to_bits(self) -> $bits {
+                ($bits::from(self.real().to_bits()) << (8 * $bytes / 2)) | $bits::from(self.dual().to_bits())
+            }
+
+            /// Creates a dual number from the bits of the real part in the high half and those of the
+            /// dual part in the low half, the inverse of `to_bits`.
+            #[inline]
+            pub fn from_bits(bits: $bits) -> DualNumber<$t> {
+                DualNumber::new($t::from_bits((bits >> (8 * $bytes / 2)) as $half), $t::from_bits(bits as $half))
+            }
+
+            /// Returns `to_bits` as a little-endian byte array, the dual part first.
+            #[inline]
+            pub fn to_le_bytes(self) -> [u8; $bytes] {
+                self.to_bits().to_le_bytes()
+            }
+
+            /// Creates a dual number from the little-endian byte array of `to_le_bytes`.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; $bytes]) -> DualNumber<$t> {
+                DualNumber::<$t>::from_bits($bits::from_le_bytes(bytes))
+            }
+        }
+    }
+}
+
+impl_bits!(
+    /// Binary encoding in a `u64`, or 8 bytes
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumberF32;
+    /// use std::fs;
+    ///
+    /// let xs = [DualNumberF32::new(1.0, -2.0), DualNumberF32::new(-0.0, f32::INFINITY)];
+    ///
+    /// assert_eq!(xs[0].to_bits(), 0x3f80_0000_c000_0000);
+    /// assert_eq!(xs[0].to_le_bytes(), [0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x80, 0x3f]);
+    ///
+    /// // Checkpoint to a file and read it back
+    /// let path = std::env::temp_dir().join(format!("dual_num_bits_{}.bin", std::process::id()));
+    /// let bytes: Vec<u8> = xs.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect();
+    /// fs::write(&path, &bytes).unwrap();
+    ///
+    /// let read: Vec<_> = fs::read(&path).unwrap()
+    ///     .chunks(8)
+    ///     .map(|c| DualNumberF32::from_le_bytes([c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]]))
+    ///     .collect();
+    /// fs::remove_file(&path).unwrap();
+    ///
+    /// assert_eq!(read.len(), 2);
+    /// for (a, b) in read.iter().zip(&xs) {
+    ///     assert_eq!(a.to_bits(), b.to_bits());
+    /// }
+    /// assert!(read[1].real().is_sign_negative());
+    ///
+    /// // Special values and NaN payloads round trip bit for bit
+    /// let specials = [0.0, -0.0, 1.0, -1.5, f32::MIN_POSITIVE, 1e-45, f32::MAX, f32::INFINITY,
+    ///                 f32::NEG_INFINITY, f32::NAN, f32::from_bits(0x7fa0_0001), f32::from_bits(0xffc1_2345)];
+    /// for &re in &specials {
+    ///     for &du in &specials {
+    ///         let x = DualNumberF32::new(re, du);
+    ///         let y = DualNumberF32::from_le_bytes(x.to_le_bytes());
+    ///
+    ///         assert_eq!((y.real().to_bits(), y.dual().to_bits()), (re.to_bits(), du.to_bits()));
+    ///         assert_eq!(DualNumberF32::from_bits(x.to_bits()).to_bits(), x.to_bits());
+    ///     }
+    /// }
+    /// ```
+    f32, u32, u64, 8
+);
+
+impl_bits!(
+    /// Binary encoding in a `u128`, or 16 bytes
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumberF64;
+    /// let x = DualNumberF64::new(1.0, -2.0);
+    ///
+    /// assert_eq!(x.to_bits(), 0x3ff0_0000_0000_0000_c000_0000_0000_0000);
+    /// assert_eq!(x.to_le_bytes(), [0, 0, 0, 0, 0, 0, 0, 0xc0, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f]);
+    ///
+    /// let specials = [0.0, -0.0, 1.0, -1.5, f64::MIN_POSITIVE, 5e-324, f64::MAX, f64::INFINITY,
+    ///                 f64::NEG_INFINITY, f64::NAN, f64::from_bits(0x7ff4_0000_0000_0001),
+    ///                 f64::from_bits(0xfff8_dead_beef_0000)];
+    /// for &re in &specials {
+    ///     for &du in &specials {
+    ///         let x = DualNumberF64::new(re, du);
+    ///         let y = DualNumberF64::from_le_bytes(x.to_le_bytes());
+    ///
+    ///         assert_eq!((y.real().to_bits(), y.dual().to_bits()), (re.to_bits(), du.to_bits()));
+    ///         assert_eq!(DualNumberF64::from_bits(x.to_bits()).to_bits(), x.to_bits());
+    ///     }
+    /// }
+    /// ```
+    f64, u64, u128, 16
+);
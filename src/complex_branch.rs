@@ -0,0 +1,65 @@
+//! Principal-branch logarithm and power for negative real arguments
+//!
+//! Enabled by the `complex-branch` feature. For a negative real `x`, the principal branch gives
+//! `Log(x) = ln|x| + iπ` and `x^p = |x|^p e^(iπp)`, with the branch cut along the negative real
+//! axis approached from above. The methods here return the real part of those complex values,
+//! and the dual part is the real part of the complex analytic derivative.
+//!
+//! For positive arguments they agree with the usual `ln` and `powf`.
+
+use super::{DualNumber, Float, FloatConst};
+
+use num_traits::Signed;
+
+impl<T> DualNumber<T> where T: Float + Signed + FloatConst {
+    /// Real part of the principal complex logarithm, `ln|x|`, with derivative `1/x`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(-2.0f64, 1.0).ln_principal();
+    ///
+    /// assert_eq!(x.real(), 2f64.ln());
+    /// assert_eq!(x.dual(), -0.5);
+    /// ```
+    pub fn ln_principal(self) -> Self {
+        DualNumber::new(self.real().abs().ln(), self.dual() / self.real())
+    }
+
+    /// Real part of the principal complex power, `Re(x^n)`.
+    ///
+    /// For negative `x` this is `|x|^n cos(πn)`, and the dual part is the real part of
+    /// `n x^(n-1) x' + x^n Log(x) n'`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// // (-x)^(1/3) at x = 8 is the principal cube root of -8, 1 + i√3
+    /// let third = DualNumber::from_real(1.0f64 / 3.0);
+    /// let y = (-DualNumber::new(8.0f64, 1.0)).powf_principal(third);
+    ///
+    /// // d/dx (-x)^(1/3) = -(1/3)(-x)^(-2/3), whose real part at x = 8 is 1/24
+    /// assert!((y.real() - 1.0).abs() < 1e-15);
+    /// assert!((y.dual() - 1.0 / 24.0).abs() < 1e-15);
+    ///
+    /// // Positive bases agree with powf
+    /// let x = DualNumber::new(2.0f64, 1.0);
+    /// assert_eq!(x.powf_principal(third).into_tuple(), x.powf(third).into_tuple());
+    /// ```
+    pub fn powf_principal(self, n: Self) -> Self {
+        if !self.real().is_sign_negative() || self.real().is_zero() {
+            return self.powf(n);
+        }
+
+        let x = self.real().abs();
+        let p = n.real();
+
+        let magnitude = x.powf(p);
+        let (s, c) = (T::PI() * p).sin_cos();
+
+        // Re(p x^(p-1)) = -p |x|^(p-1) cos(πp)
+        let base_term = -p * x.powf(p - T::one()) * c;
+        // Re(x^p Log(x)) = |x|^p (ln|x| cos(πp) - π sin(πp))
+        let exp_term = magnitude * (x.ln() * c - T::PI() * s);
+
+        DualNumber::new(magnitude * c, base_term * self.dual() + exp_term * n.dual())
+    }
+}
@@ -0,0 +1,225 @@
+//! Fast approximations of common transcendental functions
+//!
+//! These trade accuracy for speed, for real-time loops where an error around `1e-8` for `f64`, or a
+//! few ulps for `f32`, is acceptable. They are separate methods, so the precise functions stay the
+//! default and the approximations are opted into per call. Each dual part is computed from the same
+//! approximated values as the real part, so a value and its derivative stay consistent with each
+//! other, such as `fast_sin` having a dual part of exactly `dual · fast_cos(real)`.
+//!
+//! The arguments are reduced with the usual bit manipulation, and the reduced functions are evaluated
+//! with truncated Taylor series. Arguments outside of the documented ranges, as well as infinities
+//! and NaN, fall back to the precise functions.
+//!
+//! A polynomial is only used where it beats the platform's math library, see `benches/fast_math.rs`.
+//! Against glibc on x86_64, the `f64` sine and cosine take about half the time, and `fast_tanh`
+//! about 40% of the time for either type. `exp` is already as cheap as any polynomial, so `fast_exp`
+//! returns it, and `fast_tanh` is built on it. So are single precision `sin` and `cos`, which
+//! `fast_sin` and `fast_cos` return for `f32`. There is no `fast_ln`, as the division its argument
+//! reduction needs made it slower than `ln`.
+
+use super::{DualNumber, Float, FloatConst};
+
+use num_traits::Signed;
+
+/// Part types with fast approximations, which are `f32` and `f64`.
+pub(crate) trait FastMath: Float + Signed + FloatConst {
+    /// Whether `sin` and `cos` are approximated, rather than forwarded to the math library
+    const APPROXIMATE: bool;
+    /// `π/2` with enough trailing zero bits that its product with an integer is exact
+    const FRAC_PI_2_HI: Self;
+    /// `π/2 - FRAC_PI_2_HI`
+    const FRAC_PI_2_LO: Self;
+    /// `1.5 · 2^p` for `p` mantissa bits, so that `(x + ROUND) - ROUND` rounds `x` to an integer
+    const ROUND: Self;
+}
+
+macro_rules! impl_fast_math {
+    ($($t:ident, $approximate:expr, $mantissa:expr, $frac_pi_2_hi:expr);*) => {$(
+        impl FastMath for $t {
+            const APPROXIMATE: bool = $approximate;
+            const FRAC_PI_2_HI: $t = $frac_pi_2_hi;
+            const FRAC_PI_2_LO: $t = (::std::f64::consts::FRAC_PI_2 - $frac_pi_2_hi) as $t;
+            const ROUND: $t = 1.5 * (1u64 << $mantissa) as $t;
+
+        }
+    )*}
+}
+
+impl_fast_math!(
+    f32, false, 23, 201.0 / 128.0;
+    f64, true, 52, 1.570_796_326_734_125_6
+);
+
+/// Rounds `x` to the nearest integer, with ties to even, for `|x| < 2^(p-1)`.
+///
+/// Unlike `round`, this is two additions rather than a library call on targets without a rounding instruction.
+#[inline(always)]
+fn round<T: FastMath>(x: T) -> T {
+    (x + T::ROUND) - T::ROUND
+}
+
+/// Converts a coefficient to `T`.
+#[inline(always)]
+fn c<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Approximates `(sin(x), cos(x))`, reducing to `|r| ≤ π/4` and swapping by quadrant.
+#[inline]
+fn sin_cos<T: FastMath>(x: T) -> (T, T) {
+    if !T::APPROXIMATE || x.is_nan() || x.abs() > c(1e5) {
+        return x.sin_cos();
+    }
+
+    let k = round(x * T::FRAC_2_PI());
+    let r = x - k * T::FRAC_PI_2_HI - k * T::FRAC_PI_2_LO;
+    let r2 = r * r;
+
+    let s = r * (T::one() - r2 * (c::<T>(1.0 / 6.0) - r2 * (c::<T>(1.0 / 120.0) - r2 * (c::<T>(1.0 / 5040.0)
+        - r2 * c::<T>(1.0 / 362_880.0)))));
+    let c = T::one() - r2 * (c::<T>(1.0 / 2.0) - r2 * (c::<T>(1.0 / 24.0) - r2 * (c::<T>(1.0 / 720.0)
+        - r2 * c::<T>(1.0 / 40_320.0))));
+
+    match k.to_i64().unwrap() & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Approximates `tanh(x)`, with a Taylor series near zero and `(1 - t)/(1 + t)` for `t = e^(-2|x|)` elsewhere.
+#[inline]
+fn tanh<T: FastMath>(x: T) -> T {
+    let a = x.abs();
+
+    if a < c(0.25) {
+        let x2 = x * x;
+
+        return x * (T::one() - x2 * (c::<T>(1.0 / 3.0) - x2 * (c::<T>(2.0 / 15.0) - x2 * (c::<T>(17.0 / 315.0)
+            - x2 * c::<T>(62.0 / 2835.0)))));
+    }
+
+    let t = (-(a + a)).exp();
+
+    ((T::one() - t) / (T::one() + t)).copysign(x)
+}
+
+macro_rules! impl_fast_methods {
+    ($(#[$attr:meta])* $t:ident, $sin_cos:expr, $tanh:expr) => {
+        $(#[$attr])*
+        impl DualNumber<$t> {
+            /// Returns `exp`, as no polynomial accurate enough for the other methods beats the math library.
+            ///
+            /// Like `exp`, the dual part is zero at a real part of `-inf`.
+            #[inline]
+            pub fn fast_exp(self) -> Self {
+                let real = self.real().exp();
+
+                self.exp_parts(real, self.dual())
+            }
+
+            #[doc = $sin_cos]
+            #[inline]
+            pub fn fast_sin_cos(self) -> (Self, Self) {
+                let (s, c) = sin_cos(self.real());
+
+                self.sin_cos_parts(s, c)
+            }
+
+            /// Returns the sine of `fast_sin_cos`, with the same accuracy.
+            #[inline]
+            pub fn fast_sin(self) -> Self {
+                self.fast_sin_cos().0
+            }
+
+            /// Returns the cosine of `fast_sin_cos`, with the same accuracy.
+            #[inline]
+            pub fn fast_cos(self) -> Self {
+                self.fast_sin_cos().1
+            }
+
+            #[doc = $tanh]
+            ///
+            /// The dual part is `dual (1 - tanh²)`, whose absolute error is about twice as large.
+            #[inline]
+            pub fn fast_tanh(self) -> Self {
+                let real = tanh(self.real());
+
+                DualNumber::new(real, self.dual() * (1.0 - real * real))
+            }
+        }
+    }
+}
+
+impl_fast_methods!(
+    /// Fast approximations
+    ///
+    /// The error bounds are against the precise functions, over the ranges given for each method.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// fn rel(a: f64, b: f64) -> f64 {
+    ///     if b == 0.0 { a.abs() } else { ((a - b) / b).abs() }
+    /// }
+    ///
+    /// for i in 0..20_000 {
+    ///     let t = i as f64 / 20_000.0;
+    ///
+    ///     let x = DualNumber::new(20.0 * t - 10.0, 0.75);
+    ///     assert!(rel(x.fast_tanh().real(), x.tanh().real()) <= 1e-8);
+    ///     assert!((x.fast_tanh().dual() - x.tanh().dual()).abs() <= 2e-8);
+    ///
+    ///     let x = DualNumber::new(2e5 * t - 1e5, 0.75);
+    ///     let ((s, c), (ps, pc)) = (x.fast_sin_cos(), x.sin_cos());
+    ///     for &(a, b) in &[(s.real(), ps.real()), (s.dual(), ps.dual()), (c.real(), pc.real()), (c.dual(), pc.dual())] {
+    ///         assert!((a - b).abs() <= 3e-8);
+    ///     }
+    ///     assert_eq!((s.into_tuple(), c.into_tuple()), (x.fast_sin().into_tuple(), x.fast_cos().into_tuple()));
+    ///     assert_eq!(x.fast_exp().into_tuple(), x.exp().into_tuple());
+    /// }
+    ///
+    /// // The derivatives are built from the approximated values
+    /// let x = DualNumber::new(0.8f64, 1.5);
+    /// assert_eq!(x.fast_sin().dual(), 1.5 * x.fast_cos().real());
+    /// assert_eq!(x.fast_cos().dual(), -1.5 * x.fast_sin().real());
+    /// assert_eq!(x.fast_tanh().dual(), 1.5 * (1.0 - x.fast_tanh().real().powi(2)));
+    ///
+    /// // Outside of the ranges the precise functions are used
+    /// assert!(DualNumber::new(f64::NAN, 1.0).fast_sin().real().is_nan());
+    /// assert_eq!(DualNumber::new(f64::NEG_INFINITY, f64::INFINITY).fast_exp().into_tuple(), (0.0, 0.0));
+    /// assert_eq!(DualNumber::new(1e6f64, 1.0).fast_sin().into_tuple(), DualNumber::new(1e6f64, 1.0).sin().into_tuple());
+    /// ```
+    f64,
+    "Approximates `sin` and `cos` together, within an absolute error of `3e-8` for `|x| ≤ 1e5`.",
+    "Approximates `tanh`, within a relative error of `1e-8`."
+);
+
+impl_fast_methods!(
+    /// Fast approximations
+    ///
+    /// Only `fast_tanh` is approximated, the other methods return the precise functions, which are
+    /// cheaper in single precision.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for i in 0..20_000 {
+    ///     let t = i as f32 / 20_000.0;
+    ///
+    ///     let x = DualNumber::new(20.0 * t - 10.0, 0.75);
+    ///     assert!((x.fast_tanh().real() - x.tanh().real()).abs() <= 3e-7 * x.tanh().real().abs());
+    ///     assert!((x.fast_tanh().dual() - x.tanh().dual()).abs() <= 4e-7);
+    ///
+    ///     let x = DualNumber::new(200.0 * t - 100.0, 0.75);
+    ///     assert_eq!(x.fast_exp().into_tuple(), x.exp().into_tuple());
+    ///     assert_eq!(x.fast_sin().into_tuple(), x.sin().into_tuple());
+    ///     assert_eq!(x.fast_cos().into_tuple(), x.cos().into_tuple());
+    /// }
+    ///
+    /// assert_eq!(DualNumber::new(-40.0f32, 1.0).fast_tanh().into_tuple(), (-1.0, 0.0));
+    /// assert_eq!(DualNumber::new(f32::NEG_INFINITY, f32::INFINITY).fast_exp().into_tuple(), (0.0, 0.0));
+    /// ```
+    f32,
+    "Returns the precise `sin_cos`, which is cheaper than a polynomial of the same accuracy in single precision.",
+    "Approximates `tanh`, within a relative error of `3e-7`."
+);
@@ -0,0 +1,89 @@
+//! Great-circle distances on a sphere
+//!
+//! All angles are in radians, and every argument may be either a dual number or a plain scalar.
+
+use super::{DualNumber, Float, FloatConst, Zero};
+
+use num_traits::Signed;
+
+/// The haversine function, `hav(x) = sin²(x/2)`.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, FloatConst, haversin};
+/// let h = haversin(DualNumber::new(f64::FRAC_PI_2(), 1.0));
+///
+/// assert!((h.real() - 0.5).abs() < 1e-15);
+/// assert!((h.dual() - 0.5).abs() < 1e-15); // sin(x) / 2
+/// ```
+pub fn haversin<T>(x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let s = (x / T::from(2).unwrap()).sin();
+
+    s * s
+}
+
+/// Square root which is zero with a zero derivative at zero.
+///
+/// Only used on the stationary points of the haversine, where the dual part is zero as well.
+fn sqrt_or_zero<T>(x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    if x.real() > T::zero() { x.sqrt() } else { DualNumber::zero() }
+}
+
+/// Great-circle distance between `(lat1, lon1)` and `(lat2, lon2)` on a sphere of the given radius.
+///
+/// Uses the haversine formula with an `atan2` formulation, which stays accurate for both
+/// coincident and antipodal points. At those two points the distance is not differentiable,
+/// and the derivative is defined to be zero rather than NaN.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, haversine};
+/// let (paris, london) = ((48.8566f64.to_radians(), 2.3522f64.to_radians()),
+///                        (51.5074f64.to_radians(), -0.1278f64.to_radians()));
+///
+/// let d = haversine(paris.0, paris.1, london.0, london.1, 6371.0);
+/// assert!((d.real() - 343.556).abs() < 1e-3);
+///
+/// // Symmetric under swapping the endpoints
+/// let r = haversine(london.0, london.1, paris.0, paris.1, 6371.0);
+/// assert!((d.real() - r.real()).abs() < 1e-12);
+///
+/// // Coincident points
+/// let z = haversine(DualNumber::new(paris.0, 1.0), paris.1, paris.0, paris.1, 6371.0);
+/// assert_eq!(z.into_tuple(), (0.0, 0.0));
+///
+/// // Finite differences for all four partials
+/// let f = |p: [f64; 4]| haversine(p[0], p[1], p[2], p[3], 6371.0).real();
+/// let p = [paris.0, paris.1, london.0, london.1];
+///
+/// for i in 0..4 {
+///     let mut args = [DualNumber::from_real(0.0); 4];
+///     for j in 0..4 {
+///         args[j] = DualNumber::new(p[j], if i == j { 1.0 } else { 0.0 });
+///     }
+///     let exact = haversine(args[0], args[1], args[2], args[3], 6371.0).dual();
+///
+///     let h = 1e-6;
+///     let (mut hi, mut lo) = (p, p);
+///     hi[i] += h;
+///     lo[i] -= h;
+///
+///     assert!((exact - (f(hi) - f(lo)) / (2.0 * h)).abs() < 1e-4);
+/// }
+/// ```
+pub fn haversine<T, A, B, C, D, R>(lat1: A, lon1: B, lat2: C, lon2: D, radius: R) -> DualNumber<T>
+    where T: Float + Signed + FloatConst,
+          A: Into<DualNumber<T>>,
+          B: Into<DualNumber<T>>,
+          C: Into<DualNumber<T>>,
+          D: Into<DualNumber<T>>,
+          R: Into<DualNumber<T>> {
+    let (lat1, lon1, lat2, lon2) = (lat1.into(), lon1.into(), lat2.into(), lon2.into());
+
+    let a = haversin(lat2 - lat1) + lat1.cos() * lat2.cos() * haversin(lon2 - lon1);
+
+    // Rounding may push `a` slightly out of [0, 1]
+    let a = a.clamp_scalar(T::zero(), T::one());
+
+    let c = sqrt_or_zero(a).atan2(sqrt_or_zero(DualNumber::from_real(T::one()) - a)) * T::from(2).unwrap();
+
+    radius.into() * c
+}
@@ -0,0 +1,220 @@
+//! Additional hyperbolic functions not covered by `Float`
+
+use super::{DualNumber, Float, FloatConst};
+
+use num_traits::Signed;
+
+impl<T> DualNumber<T> where T: Float + Signed + FloatConst {
+    /// Simultaneously computes the hyperbolic sine and cosine, returning `(sinh(x), cosh(x))`.
+    ///
+    /// Both are computed from a single exponential, with `exp_m1` used for small `|x|`
+    /// to keep `sinh` accurate, and each dual part reuses the other function's value.
+    /// Where `e^|x|` overflows but `sinh` and `cosh` do not, they are `(e^(|x|/2) / 2) e^(|x|/2)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for &v in &[-710.0f64, -20.0, -1.5, -0.3, 1e-9, 0.0, 0.7, 3.0, 710.0] {
+    ///     let x = DualNumber::new(v, 1.0);
+    ///     let (s, c) = x.sinh_cosh();
+    ///
+    ///     assert!((s.real() - v.sinh()).abs() <= 4.0 * f64::EPSILON * v.sinh().abs());
+    ///     assert!((c.real() - v.cosh()).abs() <= 4.0 * f64::EPSILON * v.cosh());
+    ///     assert_eq!(s.dual(), c.real());
+    ///     assert_eq!(c.dual(), s.real());
+    ///
+    ///     if v.abs() < 10.0 {
+    ///         let c2 = c.real() * c.real();
+    ///         assert!((c2 - s.real() * s.real() - 1.0).abs() <= 4.0 * f64::EPSILON * c2);
+    ///     }
+    /// }
+    ///
+    /// // The same as `sinh` and `cosh` near overflow, also for a zero dual part
+    /// for &v in &[-710.0f64, 710.0, 710.4] {
+    ///     let x = DualNumber::new(v, 0.0);
+    ///     let (s, c) = x.sinh_cosh();
+    ///
+    ///     assert!(s.real().is_finite() && (s.real() - v.sinh()).abs() <= 4.0 * f64::EPSILON * v.cosh());
+    ///     assert!(c.real().is_finite() && (c.real() - v.cosh()).abs() <= 4.0 * f64::EPSILON * v.cosh());
+    ///     assert_eq!((s.dual(), c.dual()), (x.sinh().dual(), x.cosh().dual()));
+    ///     assert_eq!((s.dual(), c.dual()), (0.0, 0.0));
+    /// }
+    /// assert_eq!(DualNumber::new(711.0f64, 1.0).sinh_cosh().1.real(), f64::INFINITY);
+    /// ```
+    pub fn sinh_cosh(self) -> (Self, Self) {
+        let x = self.real();
+        let half = (T::one() + T::one()).recip();
+
+        let (s, c) = if x.abs() < T::one() {
+            // e^x - e^-x = t + t / (t + 1) with t = e^x - 1
+            let t = x.exp_m1();
+            let e = t + T::one();
+
+            (half * (t + t / e), half * (e + e.recip()))
+        } else {
+            let e = x.abs().exp();
+
+            let (s, c) = if e.is_finite() {
+                let ei = e.recip();
+
+                (half * (e - ei), half * (e + ei))
+            } else {
+                let h = (x.abs() * half).exp();
+
+                (half * h * h, half * h * h)
+            };
+
+            (if x.is_negative() { -s } else { s }, c)
+        };
+
+        (DualNumber::new(s, self.dual() * c), DualNumber::new(c, self.dual() * s))
+    }
+
+    /// Hyperbolic secant, `1/cosh(x)`, with derivative `-sech(x) tanh(x)`.
+    ///
+    /// Evaluated as `2e^(-|x|) / (1 + e^(-2|x|))`, which underflows gracefully to zero
+    /// in both parts for large `|x|` instead of overflowing `cosh`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.7f64, 1.0);
+    ///
+    /// assert!((x.sech().real() - x.cosh().recip().real()).abs() < 1e-15);
+    /// assert!((x.sech().dual() - x.cosh().recip().dual()).abs() < 1e-15);
+    ///
+    /// let h = 1e-6;
+    /// let fd = (1.0 / (0.7f64 + h).cosh() - 1.0 / (0.7f64 - h).cosh()) / (2.0 * h);
+    /// assert!((x.sech().dual() - fd).abs() < 1e-9);
+    ///
+    /// assert_eq!(DualNumber::new(1000.0f64, 1.0).sech().into_tuple(), (0.0, -0.0));
+    /// ```
+    pub fn sech(self) -> Self {
+        let x = self.real();
+        let e = (-x.abs()).exp();
+        let sech = (e + e) / (T::one() + e * e);
+
+        DualNumber::new(sech, -self.dual() * sech * x.tanh())
+    }
+
+    /// Hyperbolic cosecant, `1/sinh(x)`, with derivative `-csch(x) coth(x)`.
+    ///
+    /// Evaluated as `±2e^(-|x|) / (1 - e^(-2|x|))` with the denominator computed by `exp_m1`,
+    /// which is accurate for small `|x|` and underflows gracefully for large `|x|`.
+    /// There is a pole at zero.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.7f64, 1.0);
+    ///
+    /// assert!((x.csch().real() - x.sinh().recip().real()).abs() < 1e-15);
+    /// assert!((x.csch().dual() - x.sinh().recip().dual()).abs() < 1e-14);
+    ///
+    /// let h = 1e-6;
+    /// let fd = (1.0 / (0.7f64 + h).sinh() - 1.0 / (0.7f64 - h).sinh()) / (2.0 * h);
+    /// assert!((x.csch().dual() - fd).abs() < 1e-8);
+    ///
+    /// assert_eq!(DualNumber::new(-1000.0f64, 1.0).csch().into_tuple(), (-0.0, 0.0));
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).csch().real(), f64::INFINITY);
+    /// ```
+    pub fn csch(self) -> Self {
+        let x = self.real();
+        let e = (-x.abs()).exp();
+        let two = T::one() + T::one();
+        let csch = ((e + e) / -(-two * x.abs()).exp_m1()).copysign(x);
+
+        DualNumber::new(csch, -self.dual() * csch / x.tanh())
+    }
+
+    /// Hyperbolic cotangent, `1/tanh(x)`, with derivative `-csch²(x)`.
+    ///
+    /// For large `|x|` the value tends to `±1` and the derivative to zero. There is a pole at zero.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.7f64, 1.0);
+    ///
+    /// assert!((x.coth().real() - x.tanh().recip().real()).abs() < 1e-15);
+    /// assert!((x.coth().dual() - x.tanh().recip().dual()).abs() < 1e-14);
+    ///
+    /// assert_eq!(DualNumber::new(1000.0f64, 1.0).coth().into_tuple(), (1.0, -0.0));
+    /// assert_eq!(DualNumber::new(-1000.0f64, 1.0).coth().into_tuple(), (-1.0, -0.0));
+    /// ```
+    pub fn coth(self) -> Self {
+        let csch = self.csch().real();
+
+        DualNumber::new(self.real().tanh().recip(), -self.dual() * csch * csch)
+    }
+
+    /// Inverse hyperbolic secant, `acosh(1/x)`, with derivative `-1/(x sqrt(1 - x²))`.
+    ///
+    /// The domain is `(0, 1]`: both parts are NaN for `x < 0` and `x > 1`, and zero is a pole.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.5f64, 1.0);
+    ///
+    /// assert!((x.asech().real() - x.recip().acosh().real()).abs() < 1e-15);
+    /// assert!((x.asech().dual() + 1.0 / (0.5 * 0.75f64.sqrt())).abs() < 1e-14);
+    ///
+    /// assert_eq!(DualNumber::new(1.0f64, 1.0).asech().into_tuple(), (0.0, f64::NEG_INFINITY));
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).asech().real(), f64::INFINITY);
+    /// assert!(DualNumber::new(1.5f64, 1.0).asech().real().is_nan());
+    /// assert!(DualNumber::new(-0.5f64, 1.0).asech().dual().is_nan());
+    /// ```
+    pub fn asech(self) -> Self {
+        let x = self.real();
+
+        if x < T::zero() || x > T::one() {
+            return DualNumber::new(T::nan(), T::nan());
+        }
+
+        DualNumber::new(x.recip().acosh(),
+                        -self.dual() / (x * ((T::one() - x) * (T::one() + x)).sqrt()))
+    }
+
+    /// Inverse hyperbolic cosecant, `asinh(1/x)`, with derivative `-1/(|x| sqrt(1 + x²))`.
+    ///
+    /// Zero is a pole.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(-2.0f64, 1.0);
+    ///
+    /// assert!((x.acsch().real() - x.recip().asinh().real()).abs() < 1e-15);
+    /// assert!((x.acsch().dual() + 1.0 / (2.0 * 5f64.sqrt())).abs() < 1e-15);
+    ///
+    /// assert_eq!(DualNumber::new(1e300f64, 1.0).acsch().into_tuple(), (1e-300, -0.0));
+    /// ```
+    pub fn acsch(self) -> Self {
+        let x = self.real();
+
+        DualNumber::new(x.recip().asinh(), -self.dual() / (x.abs() * T::one().hypot(x)))
+    }
+
+    /// Inverse hyperbolic cotangent, `atanh(1/x)`, with derivative `1/(1 - x²)`.
+    ///
+    /// The domain is `|x| > 1`: both parts are NaN for `|x| < 1`, and `±1` are poles.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(2.0f64, 1.0);
+    ///
+    /// assert!((x.acoth().real() - x.recip().atanh().real()).abs() < 1e-15);
+    /// assert!((x.acoth().dual() + 1.0 / 3.0).abs() < 1e-15);
+    ///
+    /// let h = 1e-6;
+    /// let fd = ((1.0 / (2.0f64 + h)).atanh() - (1.0 / (2.0f64 - h)).atanh()) / (2.0 * h);
+    /// assert!((x.acoth().dual() - fd).abs() < 1e-9);
+    ///
+    /// assert_eq!(DualNumber::new(1.0f64, 1.0).acoth().real(), f64::INFINITY);
+    /// assert!(DualNumber::new(0.5f64, 1.0).acoth().real().is_nan());
+    /// ```
+    pub fn acoth(self) -> Self {
+        let x = self.real();
+
+        if x.abs() < T::one() {
+            return DualNumber::new(T::nan(), T::nan());
+        }
+
+        DualNumber::new(x.recip().atanh(), self.dual() / ((T::one() - x) * (T::one() + x)))
+    }
+}
@@ -0,0 +1,144 @@
+//! Interpolation and smooth step functions
+//!
+//! All functions here accept either dual numbers or plain scalars for every argument,
+//! so derivatives can be taken with respect to the edges as well as the input.
+
+use super::{DualNumber, Float, FloatConst, One, Zero};
+
+use num_traits::Signed;
+
+/// Maps `x` from `[edge0, edge1]` onto `[0, 1]`, clamping outside of the edges.
+///
+/// Once clamped, the result is a constant and its dual part is zero.
+/// Degenerate edges (`edge0 == edge1`) act as a step function at the edge.
+fn unit_step<T>(edge0: DualNumber<T>, edge1: DualNumber<T>, x: DualNumber<T>) -> DualNumber<T>
+    where T: Float + Signed + FloatConst {
+    if edge0.real() == edge1.real() {
+        return if x < edge0 { DualNumber::zero() } else { DualNumber::one() };
+    }
+
+    ((x - edge0) / (edge1 - edge0)).clamp(DualNumber::zero(), DualNumber::one())
+}
+
+/// Cubic Hermite smooth step, `3t² - 2t³` with `t` clamped to `[0, 1]` between the edges.
+///
+/// The derivative is zero at and outside of the edges, and reaches its maximum
+/// of `1.5 / (edge1 - edge0)` at the midpoint.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, smoothstep};
+/// let at = |x: f64| smoothstep(0.0, 2.0, DualNumber::new(x, 1.0));
+///
+/// assert_eq!(at(-1.0).into_tuple(), (0.0, 0.0));
+/// assert_eq!(at(0.0).into_tuple(), (0.0, 0.0));
+/// assert_eq!(at(1.0).into_tuple(), (0.5, 0.75));
+/// assert_eq!(at(2.0).into_tuple(), (1.0, 0.0));
+/// assert_eq!(at(3.0).into_tuple(), (1.0, 0.0));
+///
+/// // Derivative with respect to the lower edge
+/// let s = smoothstep(DualNumber::new(0.0, 1.0), 2.0, DualNumber::from_real(1.0));
+/// assert_eq!(s.dual(), -0.375);
+///
+/// // Degenerate edges
+/// assert_eq!(smoothstep(1.0, 1.0, DualNumber::new(1.0, 1.0)).into_tuple(), (1.0, 0.0));
+/// ```
+pub fn smoothstep<T, A, B, X>(edge0: A, edge1: B, x: X) -> DualNumber<T>
+    where T: Float + Signed + FloatConst,
+          A: Into<DualNumber<T>>,
+          B: Into<DualNumber<T>>,
+          X: Into<DualNumber<T>> {
+    let t = unit_step(edge0.into(), edge1.into(), x.into());
+
+    let two = T::from(2).unwrap();
+    let three = T::from(3).unwrap();
+
+    t * t * (DualNumber::from_real(three) - t * two)
+}
+
+/// Quintic smooth step, `6t⁵ - 15t⁴ + 10t³` with `t` clamped to `[0, 1]` between the edges.
+///
+/// Both the first and second derivatives are zero at the edges, and the first derivative
+/// reaches its maximum of `1.875 / (edge1 - edge0)` at the midpoint.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, smootherstep};
+/// let at = |x: f64| smootherstep(0.0, 2.0, DualNumber::new(x, 1.0));
+///
+/// assert_eq!(at(-1.0).into_tuple(), (0.0, 0.0));
+/// assert_eq!(at(0.0).into_tuple(), (0.0, 0.0));
+/// assert_eq!(at(1.0).into_tuple(), (0.5, 0.9375));
+/// assert_eq!(at(2.0).into_tuple(), (1.0, 0.0));
+/// assert_eq!(at(3.0).into_tuple(), (1.0, 0.0));
+/// ```
+pub fn smootherstep<T, A, B, X>(edge0: A, edge1: B, x: X) -> DualNumber<T>
+    where T: Float + Signed + FloatConst,
+          A: Into<DualNumber<T>>,
+          B: Into<DualNumber<T>>,
+          X: Into<DualNumber<T>> {
+    let t = unit_step(edge0.into(), edge1.into(), x.into());
+
+    let six = T::from(6).unwrap();
+    let ten = T::from(10).unwrap();
+    let fifteen = T::from(15).unwrap();
+
+    t * t * t * ((t * six - DualNumber::from_real(fifteen)) * t + DualNumber::from_real(ten))
+}
+
+/// Linear interpolation between `a` and `b`, `a + t(b - a)`.
+///
+/// Any of the three arguments may be a dual number, giving derivatives of
+/// `1 - t`, `t` and `b - a` with respect to `a`, `b` and `t` respectively.
+///
+/// Extrapolation is allowed for `t` outside of `[0, 1]`; see `lerp_clamped` otherwise.
+/// For `|t| <= 1` the form `a(1 - t) + bt` is used, which is exact at both endpoints
+/// in the real and dual parts.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, lerp};
+/// let a = DualNumber::new(2.0f64, 1.0);
+/// let b = DualNumber::new(5.0f64, 0.0);
+///
+/// assert_eq!(lerp(a, b, 0.0).into_tuple(), (2.0, 1.0));
+/// assert_eq!(lerp(a, b, 1.0).into_tuple(), (5.0, 0.0));
+/// assert_eq!(lerp(a, b, 0.25).into_tuple(), (2.75, 0.75));
+///
+/// // Derivatives with respect to `t` and `b`
+/// assert_eq!(lerp(2.0, 5.0, DualNumber::new(0.25, 1.0)).dual(), 3.0);
+/// assert_eq!(lerp(2.0, DualNumber::new(5.0, 1.0), 0.25).dual(), 0.25);
+///
+/// // Extrapolation
+/// assert_eq!(lerp(2.0, 5.0, DualNumber::new(2.0, 1.0)).into_tuple(), (8.0, 3.0));
+/// ```
+pub fn lerp<T, A, B, S>(a: A, b: B, t: S) -> DualNumber<T>
+    where T: Float + Signed + FloatConst,
+          A: Into<DualNumber<T>>,
+          B: Into<DualNumber<T>>,
+          S: Into<DualNumber<T>> {
+    let (a, b, t) = (a.into(), b.into(), t.into());
+
+    if t.real().abs() <= T::one() {
+        a * (DualNumber::<T>::one() - t) + b * t
+    } else {
+        a + t * (b - a)
+    }
+}
+
+/// Linear interpolation between `a` and `b` with `t` clamped to `[0, 1]`.
+///
+/// Outside of `[0, 1]` the result is pinned to an endpoint, so the derivative with respect to `t` is zero.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, lerp_clamped};
+/// let t = |t: f64| DualNumber::new(t, 1.0);
+///
+/// assert_eq!(lerp_clamped(2.0, 5.0, t(-1.0)).into_tuple(), (2.0, 0.0));
+/// assert_eq!(lerp_clamped(2.0, 5.0, t(0.5)).into_tuple(), (3.5, 3.0));
+/// assert_eq!(lerp_clamped(2.0, 5.0, t(2.0)).into_tuple(), (5.0, 0.0));
+/// ```
+pub fn lerp_clamped<T, A, B, S>(a: A, b: B, t: S) -> DualNumber<T>
+    where T: Float + Signed + FloatConst,
+          A: Into<DualNumber<T>>,
+          B: Into<DualNumber<T>>,
+          S: Into<DualNumber<T>> {
+    lerp(a, b, t.into().clamp(DualNumber::zero(), DualNumber::one()))
+}
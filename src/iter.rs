@@ -0,0 +1,133 @@
+//! Lazy differentiation of iterators
+//!
+//! The adaptors here seed each item of an iterator as it is polled, so long streams can be
+//! differentiated without collecting them first. They allocate nothing, and keep the
+//! double-ended, exact size and fused properties of the source iterator.
+
+use std::iter::FusedIterator;
+
+use super::{DualNumber, One};
+
+/// Extension methods for differentiating the items of an iterator, implemented for every `Iterator`.
+///
+/// ```rust
+/// # use dual_num::{DualIterator, DualNumber, Float, differentiate};
+/// use std::cell::Cell;
+///
+/// let xs = [0.5f64, 1.0, 2.0, 4.0];
+///
+/// // The same pairs as evaluating each point eagerly
+/// let pairs: Vec<_> = xs.iter().cloned().map_differentiate(|x| x.sin() * x).collect();
+/// for (&x, &(value, slope)) in xs.iter().zip(&pairs) {
+///     assert_eq!(value, x.sin() * x);
+///     assert_eq!(slope, differentiate(x, |x| x.sin() * x));
+/// }
+///
+/// let lifted: Vec<_> = xs.iter().cloned().lift_dual().collect();
+/// assert!(lifted.iter().zip(&xs).all(|(d, &x)| d.into_tuple() == (x, 1.0)));
+///
+/// // Nothing is evaluated until the items are polled
+/// let calls = Cell::new(0);
+/// let mut lazy = (0..1_000_000).map(|i| i as f64).map_differentiate(|x| {
+///     calls.set(calls.get() + 1);
+///     x * x
+/// });
+/// assert_eq!(calls.get(), 0);
+/// assert_eq!(lazy.next(), Some((0.0, 0.0)));
+/// assert_eq!(lazy.nth(2), Some((9.0, 6.0)));
+/// assert_eq!(calls.get(), 4);
+///
+/// // Size hints, exact lengths and reverse iteration follow the source
+/// assert_eq!(lazy.size_hint(), (999_996, Some(999_996)));
+/// assert_eq!(lazy.len(), 999_996);
+/// assert_eq!(lazy.next_back(), Some((999_999.0 * 999_999.0, 2.0 * 999_999.0)));
+///
+/// let reversed: Vec<_> = xs.iter().cloned().lift_dual().rev().map(|d| d.real()).collect();
+/// assert_eq!(reversed, vec![4.0, 2.0, 1.0, 0.5]);
+/// assert_eq!((1..4).filter(|i| i % 2 == 1).map(|i| i as f64).lift_dual().size_hint(), (0, Some(3)));
+/// ```
+pub trait DualIterator: Iterator + Sized {
+    /// Evaluates `f` on each item seeded with a dual part of one, yielding `(value, derivative)` pairs.
+    fn map_differentiate<F>(self, f: F) -> MapDifferentiate<Self, F>
+        where Self::Item: One + Copy, F: FnMut(DualNumber<Self::Item>) -> DualNumber<Self::Item> {
+        MapDifferentiate { iter: self, f }
+    }
+
+    /// Seeds each item as a dual number with a dual part of one.
+    fn lift_dual(self) -> LiftDual<Self> where Self::Item: One {
+        LiftDual { iter: self }
+    }
+}
+
+impl<I: Iterator> DualIterator for I {}
+
+/// Iterator of seeded dual numbers, created by `DualIterator::lift_dual`.
+#[derive(Debug, Clone)]
+pub struct LiftDual<I> {
+    iter: I,
+}
+
+impl<I> Iterator for LiftDual<I> where I: Iterator, I::Item: One {
+    type Item = DualNumber<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|x| DualNumber::new(x, One::one()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for LiftDual<I> where I: DoubleEndedIterator, I::Item: One {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|x| DualNumber::new(x, One::one()))
+    }
+}
+
+impl<I> ExactSizeIterator for LiftDual<I> where I: ExactSizeIterator, I::Item: One {}
+
+impl<I> FusedIterator for LiftDual<I> where I: FusedIterator, I::Item: One {}
+
+/// Iterator of `(value, derivative)` pairs, created by `DualIterator::map_differentiate`.
+#[derive(Clone)]
+pub struct MapDifferentiate<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> Iterator for MapDifferentiate<I, F>
+    where I: Iterator, I::Item: One + Copy, F: FnMut(DualNumber<I::Item>) -> DualNumber<I::Item> {
+    type Item = (I::Item, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+
+        self.iter.next().map(|x| f(DualNumber::new(x, One::one())).into_tuple())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for MapDifferentiate<I, F>
+    where I: DoubleEndedIterator, I::Item: One + Copy, F: FnMut(DualNumber<I::Item>) -> DualNumber<I::Item> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+
+        self.iter.next_back().map(|x| f(DualNumber::new(x, One::one())).into_tuple())
+    }
+}
+
+impl<I, F> ExactSizeIterator for MapDifferentiate<I, F>
+    where I: ExactSizeIterator, I::Item: One + Copy, F: FnMut(DualNumber<I::Item>) -> DualNumber<I::Item> {}
+
+impl<I, F> FusedIterator for MapDifferentiate<I, F>
+    where I: FusedIterator, I::Item: One + Copy, F: FnMut(DualNumber<I::Item>) -> DualNumber<I::Item> {}
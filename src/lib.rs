@@ -11,18 +11,85 @@
 //!
 //! ## Usage
 //!
+//! The `prelude` module re-exports everything commonly needed, and is the recommended import.
+//!
 //! ```rust
 //! extern crate dual_num;
 //!
-//! use dual_num::{DualNumber, Float, differentiate};
+//! use dual_num::prelude::*;
 //!
 //! fn main() {
 //!     // find partial derivative at x=4.0
 //!     println!("{:.5}", differentiate(4.0f64, |x| {
-//!         x.sqrt() + DualNumber::from_real(1.0)
+//!         x.sqrt() + Dual::from_real(1.0)
 //!     })); // 0.25000
 //! }
 //! ```
+//!
+//! ## Non-`Copy` parts
+//!
+//! The arithmetic operators only need `T: Num + Clone`, so exact or arbitrary precision types such as
+//! big rationals work as parts. Available for such types are `new`, `into_tuple`, the `*_ref` accessors,
+//! `differentiate_from`, `+`, `-`, `*` and `/` between dual numbers and with scalars, negation, and the
+//! `Zero`, `One` and `Num` traits. Everything else needs `Copy` parts: the `real` and `dual` getters,
+//! `differentiate`, `Signed`, and everything that needs `Float`, including `sqrt`, `exp`, `ln`, the
+//! trigonometric functions and `powi`/`powf`.
+//!
+//! ```rust
+//! extern crate dual_num;
+//! extern crate num_traits;
+//!
+//! use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+//! use dual_num::{DualNumber, Num, One, Zero, differentiate_from};
+//!
+//! /// An exact rational, deliberately not `Copy`
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct Ratio(Box<(i128, i128)>);
+//!
+//! fn ratio(n: i128, d: i128) -> Ratio {
+//!     let gcd = |mut a: i128, mut b: i128| { while b != 0 { let t = a % b; a = b; b = t; } a.abs().max(1) };
+//!     let g = gcd(n, d) * d.signum();
+//!     Ratio(Box::new((n / g, d / g)))
+//! }
+//!
+//! impl Add for Ratio { type Output = Ratio; fn add(self, o: Ratio) -> Ratio { ratio(self.0 .0 * o.0 .1 + o.0 .0 * self.0 .1, self.0 .1 * o.0 .1) } }
+//! impl Sub for Ratio { type Output = Ratio; fn sub(self, o: Ratio) -> Ratio { self + -o } }
+//! impl Mul for Ratio { type Output = Ratio; fn mul(self, o: Ratio) -> Ratio { ratio(self.0 .0 * o.0 .0, self.0 .1 * o.0 .1) } }
+//! impl Div for Ratio { type Output = Ratio; fn div(self, o: Ratio) -> Ratio { ratio(self.0 .0 * o.0 .1, self.0 .1 * o.0 .0) } }
+//! impl Rem for Ratio { type Output = Ratio; fn rem(self, _: Ratio) -> Ratio { ratio(0, 1) } }
+//! impl Neg for Ratio { type Output = Ratio; fn neg(self) -> Ratio { ratio(-self.0 .0, self.0 .1) } }
+//! impl Zero for Ratio { fn zero() -> Ratio { ratio(0, 1) } fn is_zero(&self) -> bool { self.0 .0 == 0 } }
+//! impl One for Ratio { fn one() -> Ratio { ratio(1, 1) } }
+//! impl Num for Ratio {
+//!     type FromStrRadixErr = std::num::ParseIntError;
+//!     fn from_str_radix(s: &str, radix: u32) -> Result<Ratio, Self::FromStrRadixErr> {
+//!         i128::from_str_radix(s, radix).map(|n| ratio(n, 1))
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let x = DualNumber::new(ratio(2, 3), ratio(1, 1));
+//!     let y = DualNumber::new(ratio(-1, 4), ratio(1, 2));
+//!
+//!     assert_eq!((x.clone() + y.clone()).into_tuple(), (ratio(5, 12), ratio(3, 2)));
+//!     assert_eq!((x.clone() - y.clone()).into_tuple(), (ratio(11, 12), ratio(1, 2)));
+//!     assert_eq!((x.clone() * y.clone()).into_tuple(), (ratio(-1, 6), ratio(1, 12)));
+//!     assert_eq!((x.clone() / y.clone()).into_tuple(), (ratio(-8, 3), ratio(-28, 3)));
+//!     assert_eq!((x.clone() * ratio(3, 1) + ratio(1, 2)).into_tuple(), (ratio(5, 2), ratio(3, 1)));
+//!     assert_eq!((-x.clone()).into_tuple(), (ratio(-2, 3), ratio(-1, 1)));
+//!     assert_eq!(DualNumber::<Ratio>::one().into_tuple(), (ratio(1, 1), ratio(0, 1)));
+//!
+//!     // p(x) = 3x³ - x/2 + 1/3, with p'(x) = 9x² - 1/2, exactly
+//!     let p = |x: DualNumber<Ratio>| {
+//!         x.clone() * x.clone() * x.clone() * ratio(3, 1) - x / ratio(2, 1) + ratio(1, 3)
+//!     };
+//!     let y = differentiate_from(x, p);
+//!     assert_eq!(y.into_tuple(), (ratio(8, 9), ratio(7, 2)));
+//! }
+//! ```
+
+// The `ε` of the `dual!` macro is the only Greek identifier, which this lint flags as confusable with `e`
+#![allow(mixed_script_confusables)]
 
 // Note that the somewhat excessive #[inline] annotations are not harmful here,
 // and can improve cross-crate inlining.
@@ -35,11 +102,54 @@ extern crate num_traits;
 use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
 use std::cmp::Ordering;
 use std::num::FpCategory;
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fmt::{Debug, Display, LowerExp, Formatter, Result as FmtResult};
+use std::error::Error;
+use std::str::FromStr;
+use std::convert::TryFrom;
 
 pub use num_traits::{One, Zero, Float, FloatConst, Num};
 
-use num_traits::{Signed, Unsigned, NumCast, ToPrimitive, FromPrimitive};
+use num_traits::{Signed, Unsigned, NumCast, ToPrimitive, FromPrimitive, PrimInt, AsPrimitive};
+
+#[macro_use]
+mod macros;
+
+mod interpolate;
+mod geodesy;
+mod trig;
+mod hyperbolic;
+mod accurate;
+mod polynomial;
+mod pow_base;
+mod special;
+mod log_domain;
+mod loss;
+mod normalization;
+mod buffer;
+mod iter;
+mod fast_math;
+mod bits;
+
+pub mod prelude;
+
+#[cfg(feature = "complex-branch")]
+mod complex_branch;
+#[cfg(feature = "newton-div")]
+mod division;
+
+pub use interpolate::{smoothstep, smootherstep, lerp, lerp_clamped};
+pub use geodesy::{haversin, haversine};
+pub use polynomial::{horner, polyval, polyval_dual_coeffs, polyder, legendre_p, legendre_p_assoc,
+                     legendre_p_assoc_normalized, chebyshev_t, chebyshev_eval, hermite_h, hermite_h_scaled,
+                     laguerre_l, laguerre_l_gen};
+pub use pow_base::{PowBase, exp_pow};
+pub use special::{ln_pochhammer, pochhammer, ln_binomial};
+pub use log_domain::{logaddexp, logsumexp, LogSumExpAccumulator, softmax, softmax_into};
+pub use loss::{cross_entropy, cross_entropy_soft, nll_loss, bce_with_logits, bce_with_logits_mean, mse, mse_sum,
+               mae, mae_sum, huber, huber_mean, pseudo_huber};
+pub use normalization::{layer_norm, layer_norm_affine};
+pub use buffer::DualBuffer;
+pub use iter::{DualIterator, LiftDual, MapDifferentiate};
 
 /// Dual Number structure
 ///
@@ -47,9 +157,16 @@ use num_traits::{Signed, Unsigned, NumCast, ToPrimitive, FromPrimitive};
 /// it only compares the real part.
 ///
 /// Additionally, `min` and `max` only compare the real parts, and keep the dual parts.
+/// As with the primitive floats, a NaN real part loses to any other operand.
 ///
 /// Lastly, the `Rem` remainder operator is not correctly or fully defined for `DualNumber`, and will panic.
-#[derive(Debug, Clone, Copy)]
+///
+/// New code should prefer the shorter `Dual`, `Dual32` and `Dual64` aliases.
+///
+/// The layout is guaranteed to be that of `[T; 2]`, the real part followed by the dual part, so
+/// slices of dual numbers can be passed to C or viewed as arrays with `AsRef<[T; 2]>`.
+#[derive(Clone, Copy)]
+#[repr(C)]
 pub struct DualNumber<T>(T, T);
 
 /// Convenience type
@@ -58,15 +175,281 @@ pub type DualNumberF32 = DualNumber<f32>;
 /// Convenience type
 pub type DualNumberF64 = DualNumber<f64>;
 
+/// Short name for `DualNumber`, and the preferred name in new code.
+///
+/// The aliases work everywhere the long name does, including constructors, associated constants,
+/// trait implementations, the macros and the helper functions. A future major release will make
+/// `DualNumber` the alias of `Dual` instead, which changes nothing for code using either name.
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate dual_num;
+///
+/// use dual_num::{Dual, Dual32, Dual64, DualBuffer, DualNumber, Float, FloatConst, One, differentiate, seed_each};
+///
+/// fn norm<T: Float + FloatConst + num_traits::Signed>(x: Dual<T>, y: Dual<T>) -> Dual<T> {
+///     x.hypot(y)
+/// }
+/// # extern crate num_traits;
+///
+/// # fn main() {
+/// let x: Dual64 = Dual::new(3.0, 1.0);
+/// let long: DualNumber<f64> = x;
+/// assert_eq!(norm(long, dual!(4.0)).into_tuple(), (5.0, 0.6));
+///
+/// const SEED: Dual64 = dual!(2.0 + 1.0 ε);
+/// assert_dual_eq!(SEED.powi(3), 8.0, 12.0, 0.0);
+/// assert_eq!(Dual64::ZERO.into_tuple(), (0.0, 0.0));
+/// assert_eq!(Dual32::PI().into_tuple(), (std::f32::consts::PI, 0.0));
+/// assert_eq!(<Dual<i32> as One>::one().into_tuple(), (1, 0));
+///
+/// assert_eq!(differentiate(2.0f64, |x: Dual64| x * x), 4.0);
+/// let seeds: Vec<Vec<Dual64>> = seed_each(&[1.0, 2.0]);
+/// let batch: DualBuffer<f64> = seeds[0].iter().cloned().collect();
+/// assert_eq!(batch.get(0).into_tuple(), (1.0, 1.0));
+/// # }
+/// ```
+pub type Dual<T> = DualNumber<T>;
+
+/// `Dual` with `f32` parts
+pub type Dual32 = Dual<f32>;
+
+/// `Dual` with `f64` parts
+pub type Dual64 = Dual<f64>;
+
 /// Evaluates the function using dual numbers to get the partial derivative at the input point
 pub fn differentiate<T: One + Copy, F>(x: T, f: F) -> T where F: Fn(DualNumber<T>) -> DualNumber<T> {
     f(DualNumber::new(x, T::one())).dual()
 }
 
+/// Evaluates the function on an already seeded dual number, such as the output of an upstream computation
+///
+/// The dual part of the result is the derivative of the whole pipeline with respect to the original seed.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, differentiate_from};
+/// // Two stages, g(x) = x² and f(u) = sin(u), composed at x = 1.5
+/// let stage1 = differentiate_from(DualNumber::new(1.5f64, 1.0), |x| x * x);
+/// let stage2 = differentiate_from(stage1, |u| u.sin());
+///
+/// assert_eq!(stage1.into_tuple(), (2.25, 3.0));
+/// assert_eq!(stage2.real(), 2.25f64.sin());
+/// assert_eq!(stage2.dual(), 3.0 * 2.25f64.cos());
+/// ```
+pub fn differentiate_from<T, F>(seed: DualNumber<T>, f: F) -> DualNumber<T> where F: Fn(DualNumber<T>) -> DualNumber<T> {
+    f(seed)
+}
+
+/// Errors produced by the fallible functions of this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DualError {
+    /// The resulting derivative was NaN or infinite
+    NonFinite,
+    /// A class index was not smaller than the number of classes
+    TargetOutOfRange {
+        /// The requested index
+        target: usize,
+        /// The number of classes
+        len: usize,
+    },
+    /// Two slices which should correspond element-wise have different lengths
+    LengthMismatch {
+        /// The length of the first slice
+        expected: usize,
+        /// The length of the second slice
+        found: usize,
+    },
+    /// A finite value was too large for the target type of a conversion
+    Overflow,
+}
+
+impl Display for DualError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            DualError::NonFinite => f.write_str("derivative is not finite"),
+            DualError::TargetOutOfRange { target, len } => {
+                write!(f, "target {} is out of range for {} classes", target, len)
+            }
+            DualError::LengthMismatch { expected, found } => {
+                write!(f, "expected a length of {}, found {}", expected, found)
+            }
+            DualError::Overflow => f.write_str("value out of range for the target type"),
+        }
+    }
+}
+
+impl Error for DualError {}
+
+/// Like `differentiate`, but returns an error if the derivative is NaN or infinite.
+///
+/// This distinguishes a derivative that is undefined at the given point from a finite result.
+///
+/// ```rust
+/// # use dual_num::{DualError, Float, try_differentiate};
+/// assert_eq!(try_differentiate(4.0f64, |x| x.sqrt()), Ok(0.25));
+/// assert_eq!(try_differentiate(0.0f64, |x| x.sqrt()), Err(DualError::NonFinite));
+/// ```
+pub fn try_differentiate<T: Float, F>(x: T, f: F) -> Result<T, DualError> where F: Fn(DualNumber<T>) -> DualNumber<T> {
+    let d = differentiate(x, f);
+
+    if d.is_finite() { Ok(d) } else { Err(DualError::NonFinite) }
+}
+
+/// Seeds each of `N` independent variables for a manual gradient loop.
+///
+/// Row `i` of the result holds all of the variables, with only the `i`-th one seeded with a dual part of one.
+/// Evaluating a function on row `i` therefore yields its partial derivative with respect to `x[i]`.
+///
+/// ```rust
+/// # use dual_num::seed_variables;
+/// let seeded = seed_variables([3.0, 5.0]);
+///
+/// assert_eq!(seeded[0][0].into_tuple(), (3.0, 1.0));
+/// assert_eq!(seeded[0][1].into_tuple(), (5.0, 0.0));
+/// assert_eq!(seeded[1][0].into_tuple(), (3.0, 0.0));
+/// assert_eq!(seeded[1][1].into_tuple(), (5.0, 1.0));
+///
+/// // Gradient of x * y² at (3, 5)
+/// let grad: Vec<f64> = seeded.iter().map(|v| (v[0] * v[1] * v[1]).dual()).collect();
+/// assert_eq!(grad, vec![25.0, 30.0]);
+/// ```
+pub fn seed_variables<const N: usize>(x: [f64; N]) -> [[DualNumberF64; N]; N] {
+    std::array::from_fn(|i| std::array::from_fn(|j| DualNumber::new(x[j], if i == j { 1.0 } else { 0.0 })))
+}
+
+/// Like `seed_variables`, but for a number of variables only known at runtime.
+///
+/// Row `i` of the result is the whole input with only the `i`-th variable seeded with a dual part of one.
+///
+/// ```rust
+/// # use dual_num::{seed_each, seed_variables};
+/// let seeded = seed_each(&[3.0, 5.0, -1.0]);
+///
+/// for (i, row) in seeded.iter().enumerate() {
+///     let parts: Vec<_> = row.iter().map(|x| x.into_tuple()).collect();
+///     let expected: Vec<_> = [3.0, 5.0, -1.0].iter().enumerate()
+///         .map(|(j, &x)| (x, if i == j { 1.0 } else { 0.0 }))
+///         .collect();
+///
+///     assert_eq!(parts, expected);
+/// }
+/// assert_eq!(seeded.len(), 3);
+///
+/// // Gradient of x y z at (3, 5, -1)
+/// let grad: Vec<f64> = seeded.iter().map(|v| (v[0] * v[1] * v[2]).dual()).collect();
+/// assert_eq!(grad, vec![-5.0, -3.0, 15.0]);
+///
+/// let fixed: Vec<Vec<_>> = seed_variables([3.0, 5.0, -1.0]).iter().map(|row| row.to_vec()).collect();
+/// assert_eq!(seeded, fixed);
+/// assert!(seed_each(&[]).is_empty());
+/// ```
+pub fn seed_each(xs: &[f64]) -> Vec<Vec<DualNumberF64>> {
+    (0..xs.len())
+        .map(|i| xs.iter().enumerate().map(|(j, &x)| DualNumber::new(x, if i == j { 1.0 } else { 0.0 })).collect())
+        .collect()
+}
+
+/// Integrates `f` over `[a, b]` with composite Simpson's rule on `n` intervals, returning the integral
+/// and, in the dual part, its sensitivity to whichever parameter `f` seeds.
+///
+/// The dual part is the same rule applied to the dual parts of `f`, which is the derivative of the
+/// quadrature. Both parts have an error of order `h⁴` with `h = (b - a) / n`, and cubic polynomials are
+/// integrated exactly.
+///
+/// # Panics
+///
+/// Panics if `n` is zero or odd, since Simpson's rule pairs up the intervals.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, simpson_with_sensitivity};
+/// // ∫₀² p x³ + x² dx = 4p + 8/3, with a sensitivity of 4 to p
+/// let p = DualNumber::new(1.5, 1.0);
+/// let integral = simpson_with_sensitivity(0.0, 2.0, 2, |x| p * x.powi(3) + x * x);
+///
+/// assert!((integral.real() - (6.0 + 8.0 / 3.0)).abs() < 1e-14);
+/// assert!((integral.dual() - 4.0).abs() < 1e-14);
+///
+/// // ∫₀^π sin(kx) dx = (1 - cos(kπ)) / k, with derivative (π k sin(kπ) + cos(kπ) - 1) / k²
+/// let k = DualNumber::new(0.5, 1.0);
+/// let pi = std::f64::consts::PI;
+/// let integral = simpson_with_sensitivity(0.0, pi, 64, |x| (k * x).sin());
+///
+/// assert!((integral.real() - (1.0 - (0.5 * pi).cos()) / 0.5).abs() < 1e-8);
+/// assert!((integral.dual() - (pi * 0.5 * (0.5 * pi).sin() + (0.5 * pi).cos() - 1.0) / 0.25).abs() < 1e-7);
+/// ```
+///
+/// ```rust,should_panic
+/// # use dual_num::{DualNumber, simpson_with_sensitivity};
+/// simpson_with_sensitivity(0.0, 1.0, 3, |x| DualNumber::new(x, 1.0));
+/// ```
+pub fn simpson_with_sensitivity<F>(a: f64, b: f64, n: usize, f: F) -> DualNumberF64 where F: Fn(f64) -> DualNumberF64 {
+    assert!(n > 0 && n & 1 == 0, "Simpson's rule needs a positive, even number of intervals, got {}", n);
+
+    let h = (b - a) / n as f64;
+
+    let inner = (1..n).fold(DualNumber::new(0.0, 0.0), |sum, i| {
+        sum + f(a + i as f64 * h) * if i & 1 == 1 { 4.0 } else { 2.0 }
+    });
+
+    (f(a) + inner + f(b)) * (h / 3.0)
+}
+
+/// Returns `if_true` if `mask` is set and `if_false` otherwise, without branching.
+///
+/// The operand is picked by indexing rather than with an `if`, which compiles to a conditional
+/// move or a blend, so loops built from it vectorize even when the mask varies between elements.
+/// This is what `Float::abs` is built on.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, select};
+/// let x = DualNumber::new(-2.0f64, 1.0);
+/// let y = DualNumber::new(3.0f64, 0.5);
+///
+/// assert_eq!(select(true, x, y).into_tuple(), (-2.0, 1.0));
+/// assert_eq!(select(false, x, y).into_tuple(), (3.0, 0.5));
+///
+/// // A branch-free ReLU
+/// let relu = |x: DualNumber<f64>| select(x.real() > 0.0, x, DualNumber::from_real(0.0));
+/// assert_eq!(relu(y).into_tuple(), (3.0, 0.5));
+/// assert_eq!(relu(x).into_tuple(), (0.0, 0.0));
+/// ```
+#[inline(always)]
+pub fn select<T: Copy>(mask: bool, if_true: DualNumber<T>, if_false: DualNumber<T>) -> DualNumber<T> {
+    [if_false, if_true][mask as usize]
+}
+
 impl<T> DualNumber<T> {
     /// Create a new dual number from its real and dual parts.
+    ///
+    /// This is a `const fn`, so it can build constants and static tables, along with `from_real_const`
+    /// and the `ZERO`, `ONE` and `EPSILON` constants of `DualNumber<f32>` and `DualNumber<f64>`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, DualNumberF64, Float};
+    /// const SEED: DualNumberF64 = DualNumber::new(0.5, 1.0);
+    /// const HALF: DualNumberF64 = DualNumberF64::from_real_const(0.5);
+    /// const PARTS: (f64, f64) = (*SEED.real_ref(), *SEED.dual_ref());
+    ///
+    /// // Seeded Gauss-Legendre nodes on [-1, 1]
+    /// static NODES: [DualNumberF64; 3] = [
+    ///     DualNumber::new(-0.7745966692414834, 1.0),
+    ///     DualNumberF64::EPSILON,
+    ///     DualNumber::new(0.7745966692414834, 1.0),
+    /// ];
+    /// const WEIGHTS: [f64; 3] = [5.0 / 9.0, 8.0 / 9.0, 5.0 / 9.0];
+    ///
+    /// // ∫₋₁¹ d/dx exp(x) dx = e - 1/e
+    /// let integral: f64 = NODES.iter().zip(&WEIGHTS).map(|(x, w)| x.exp().dual() * w).sum();
+    /// assert!((integral - (1f64.exp() - (-1f64).exp())).abs() < 1e-3);
+    ///
+    /// assert_eq!(PARTS, (0.5, 1.0));
+    /// assert_eq!(HALF.into_tuple(), (0.5, 0.0));
+    /// assert_eq!(DualNumber::<f64>::ZERO.into_tuple(), (0.0, 0.0));
+    /// assert_eq!(DualNumber::<f32>::ONE.into_tuple(), (1.0, 0.0));
+    /// assert_eq!((DualNumberF64::EPSILON * DualNumberF64::EPSILON).into_tuple(), (0.0, 0.0));
+    /// ```
     #[inline]
-    pub fn new(real: T, dual: T) -> DualNumber<T> {
+    pub const fn new(real: T, dual: T) -> DualNumber<T> {
         DualNumber(real, dual)
     }
 
@@ -78,7 +461,69 @@ impl<T> DualNumber<T> {
         DualNumber::new(real, T::zero())
     }
 
+    /// Creates a constant, a dual number whose dual part is zero.
+    ///
+    /// This is the same as `from_real`, under the name that reads better where a value is meant not
+    /// to depend on the seeded variable.
+    #[inline]
+    pub fn constant(value: T) -> DualNumber<T> where T: Zero {
+        DualNumber::from_real(value)
+    }
+
+    /// Sets the dual part to zero, so that the value is treated as a constant from then on.
+    ///
+    /// This is the "stop gradient" of machine learning frameworks: nothing computed from the result
+    /// carries a derivative through it.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(1.5f64, 1.0);
+    ///
+    /// // d/dx (x² + sin(x)) with the sine treated as a constant
+    /// let y = x * x + x.sin().detach();
+    /// assert_eq!(y.into_tuple(), (1.5 * 1.5 + 1.5.sin(), 3.0));
+    ///
+    /// // Everything downstream of a detached value is constant
+    /// let z = (x.exp().detach() * 2.0).ln().powi(3);
+    /// assert!(z.is_constant() && !x.is_constant());
+    /// assert_eq!(DualNumber::constant(2.0).into_tuple(), DualNumber::from_real(2.0).into_tuple());
+    /// ```
+    #[inline]
+    pub fn detach(self) -> DualNumber<T> where T: Zero {
+        DualNumber::new(self.0, T::zero())
+    }
+
+    /// Returns true if the dual part is zero, that is if the value does not depend on the seeded
+    /// variable.
+    #[inline]
+    pub fn is_constant(&self) -> bool where T: Zero {
+        self.1.is_zero()
+    }
+
+    /// Returns true if both the real and dual parts are zero.
+    ///
+    /// This differs from `Zero::is_zero`, which like the comparison operators only looks at the real part.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Zero};
+    /// let x = DualNumber::new(0.0, 5.0);
+    ///
+    /// assert!(x.is_zero());
+    /// assert!(!x.is_exactly_zero());
+    ///
+    /// let z = DualNumber::new(0.0, 0.0);
+    ///
+    /// assert!(z.is_zero());
+    /// assert!(z.is_exactly_zero());
+    /// ```
+    #[inline]
+    pub fn is_exactly_zero(&self) -> bool where T: Zero {
+        self.0.is_zero() && self.1.is_zero()
+    }
+
     /// Returns both real and dual parts as a tuple
+    ///
+    /// Unlike the other accessors this is not a `const fn`, since the parts may need dropping.
     #[inline]
     pub fn into_tuple(self) -> (T, T) {
         (self.0, self.1)
@@ -86,11 +531,11 @@ impl<T> DualNumber<T> {
 
     /// Returns a reference to the real part
     #[inline]
-    pub fn real_ref(&self) -> &T { &self.0 }
+    pub const fn real_ref(&self) -> &T { &self.0 }
 
     /// Returns a reference to the dual part
     #[inline]
-    pub fn dual_ref(&self) -> &T { &self.1 }
+    pub const fn dual_ref(&self) -> &T { &self.1 }
 
     /// Returns a mutable reference to the real part
     #[inline]
@@ -100,6 +545,86 @@ impl<T> DualNumber<T> {
     #[inline]
     pub fn dual_mut(&mut self) -> &mut T { &mut self.1 }
 
+    /// Returns the dual number with its real part replaced by `real`.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let x = DualNumber::new(1.0f64, 2.0);
+    ///
+    /// assert_eq!(x.with_real(3.0).with_dual(-4.0).into_tuple(), (3.0, -4.0));
+    /// assert_eq!(DualNumber::from(5.0f64).with_dual(0.5).into_tuple(), (5.0, 0.5));
+    ///
+    /// // Seeding overwrites an existing dual part rather than adding to it
+    /// assert_eq!(DualNumber::from(2.0f64).seeded().into_tuple(), (2.0, 1.0));
+    /// assert_eq!(x.seeded().seeded().into_tuple(), (1.0, 1.0));
+    ///
+    /// // Lifting plain values in an iterator pipeline
+    /// let xs: Vec<_> = [0.5f64, 1.5].iter().map(|&x| DualNumber::from(x).seeded() * x).collect();
+    /// assert_eq!(xs.iter().map(|x| x.into_tuple()).collect::<Vec<_>>(), vec![(0.25, 0.5), (2.25, 1.5)]);
+    /// ```
+    #[inline]
+    pub fn with_real(self, real: T) -> DualNumber<T> {
+        DualNumber::new(real, self.1)
+    }
+
+    /// Returns the dual number with its dual part replaced by `dual`.
+    #[inline]
+    pub fn with_dual(self, dual: T) -> DualNumber<T> {
+        DualNumber::new(self.0, dual)
+    }
+
+    /// Returns the dual number with a dual part of one, making it the variable to differentiate by.
+    #[inline]
+    pub fn seeded(self) -> DualNumber<T> where T: One {
+        DualNumber::new(self.0, T::one())
+    }
+
+    /// Returns the conjugate of the dual number, `x - x'ε`.
+    ///
+    /// This only negates the dual part, so it works for any part type with negation, including the
+    /// signed integers.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// assert_eq!(DualNumber::new(3i32, -4).conjugate().into_tuple(), (3, 4));
+    /// assert_eq!(DualNumber::new(2.5f64, 0.5).conjugate().into_tuple(), (2.5, -0.5));
+    ///
+    /// // The product with the conjugate is real
+    /// let x = DualNumber::new(7i64, 3);
+    /// assert_eq!((x * x.conjugate()).into_tuple(), (49, 0));
+    /// ```
+    #[inline]
+    pub fn conjugate(self) -> DualNumber<T> where T: Neg<Output = T> {
+        DualNumber::new(self.0, -self.1)
+    }
+
+    /// Converts both parts to another numeric type with `NumCast`, or returns `None` if either part
+    /// cannot be represented.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(2.5f32, -1.0);
+    ///
+    /// assert_eq!(x.cast::<f64>().unwrap().into_tuple(), (2.5, -1.0));
+    /// assert_eq!(DualNumber::new(7.9f64, -3.2).cast::<i32>().unwrap().into_tuple(), (7, -3));
+    /// assert!(DualNumber::new(1.0f64, f64::NAN).cast::<i32>().is_none());
+    /// assert!(DualNumber::new(-1.0f64, 0.0).cast::<u8>().is_none());
+    ///
+    /// // Into nested dual numbers, each part becoming a constant
+    /// let nested = x.cast::<DualNumber<f64>>().unwrap();
+    /// assert_eq!((nested.real().into_tuple(), nested.dual().into_tuple()), ((2.5, 0.0), (-1.0, 0.0)));
+    ///
+    /// // A mixed precision pipeline: accumulate in f64, store in f32
+    /// let samples: Vec<DualNumber<f32>> = (1..=4).map(|i| DualNumber::new(i as f32 * 0.1, 1.0)).collect();
+    /// let sum = samples.iter().map(|&s| DualNumber::<f64>::from(s).sin()).fold(DualNumber::from_real(0.0), |a, b| a + b);
+    /// let stored = sum.cast::<f32>().unwrap();
+    /// assert!((stored.real() as f64 - sum.real()).abs() < 1e-7 && (stored.dual() as f64 - sum.dual()).abs() < 1e-6);
+    /// ```
+    #[inline]
+    pub fn cast<U: NumCast>(self) -> Option<DualNumber<U>> where T: ToPrimitive {
+        Some(DualNumber::new(U::from(self.0)?, U::from(self.1)?))
+    }
+
     /// Convenience method to take a closure (or any function) that can operate on the dual number in place
     #[inline(always)]
     pub fn map<F>(self, mapper: F) -> Self where F: Fn(DualNumber<T>) -> DualNumber<T> {
@@ -111,36 +636,1311 @@ impl<T> DualNumber<T> {
     pub fn map_parts<F>(self, mapper: F) -> Self where F: Fn(T, T) -> DualNumber<T> {
         mapper(self.0, self.1)
     }
+
+    /// Convenience method to take a closure (or any function) that can operate on the parts of two dual numbers,
+    /// given as `(self.real, self.dual, other.real, other.dual)`
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// // Weighted blend 0.25 * a + 0.75 * b
+    /// let blend = |ar: f64, ad: f64, br: f64, bd: f64| {
+    ///     DualNumber::new(0.25 * ar + 0.75 * br, 0.25 * ad + 0.75 * bd)
+    /// };
+    ///
+    /// let x = DualNumber::new(4.0, 1.0).map2(DualNumber::new(8.0, 0.0), blend);
+    ///
+    /// assert_eq!(x.into_tuple(), (7.0, 0.25));
+    /// ```
+    #[inline(always)]
+    pub fn map2<F>(self, other: Self, mapper: F) -> Self where F: Fn(T, T, T, T) -> DualNumber<T> {
+        mapper(self.0, self.1, other.0, other.1)
+    }
+
+    /// Applies `f` to the real part, keeping the dual part.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// // Quantize the value to steps of 0.25, keeping the derivative of the unquantized function
+    /// let y = DualNumber::new(1.3f64, 1.0).sin().map_real(|v| (v * 4.0).round() / 4.0);
+    ///
+    /// assert_eq!(y.into_tuple(), (1.0, 1.3f64.cos()));
+    /// ```
+    #[inline]
+    pub fn map_real<F>(self, f: F) -> Self where F: FnOnce(T) -> T {
+        DualNumber(f(self.0), self.1)
+    }
+
+    /// Applies `f` to the dual part, keeping the real part.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// // Clip the derivative to [-1, 1]
+    /// let clip = |d: f64| d.max(-1.0).min(1.0);
+    ///
+    /// assert_eq!(DualNumber::new(2.0f64, 5.0).map_dual(clip).into_tuple(), (2.0, 1.0));
+    /// assert_eq!(DualNumber::new(2.0f64, -0.5).map_dual(clip).into_tuple(), (2.0, -0.5));
+    ///
+    /// // The closures are `FnOnce`, so they can move out of their captures, and the parts need not be `Copy`
+    /// let suffix = String::from("'");
+    /// let x = DualNumber::new(String::from("f"), String::from("df")).map_dual(move |d| d + &suffix);
+    /// assert_eq!(x.into_tuple(), (String::from("f"), String::from("df'")));
+    /// ```
+    #[inline]
+    pub fn map_dual<F>(self, f: F) -> Self where F: FnOnce(T) -> T {
+        DualNumber(self.0, f(self.1))
+    }
+
+    /// Combines two dual numbers part-wise, as `(f_real(self.real, other.real), f_dual(self.dual, other.dual))`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let (a, b) = (DualNumber::new(3.0f64, 0.5), DualNumber::new(-1.0f64, 2.0));
+    ///
+    /// // Larger value, with the derivative of largest magnitude
+    /// let c = a.zip_parts(b, f64::max, |x, y| if x.abs() >= y.abs() { x } else { y });
+    /// assert_eq!(c.into_tuple(), (3.0, 2.0));
+    ///
+    /// // The same as map2 with the matching closure, and map and map_parts are unchanged
+    /// let sum = a.map2(b, |ar, ad, br, bd| DualNumber::new(ar + br, ad + bd));
+    /// assert_eq!(a.zip_parts(b, |x, y| x + y, |x, y| x + y).into_tuple(), sum.into_tuple());
+    /// assert_eq!(a.map(|x| x * 2.0).into_tuple(), (6.0, 1.0));
+    /// assert_eq!(a.map_parts(|r, d| DualNumber::new(d, r)).into_tuple(), (0.5, 3.0));
+    ///
+    /// let parts = DualNumber::new(vec![1], vec![2]).zip_parts(DualNumber::new(vec![3], vec![4]),
+    ///     |mut x, y| { x.extend(y); x }, |x, mut y| { y.extend(x); y });
+    /// assert_eq!(parts.into_tuple(), (vec![1, 3], vec![4, 2]));
+    /// ```
+    #[inline]
+    pub fn zip_parts<F, G>(self, other: Self, f_real: F, f_dual: G) -> Self
+        where F: FnOnce(T, T) -> T, G: FnOnce(T, T) -> T {
+        DualNumber(f_real(self.0, other.0), f_dual(self.1, other.1))
+    }
+}
+
+impl<T: Zero> From<T> for DualNumber<T> {
+    fn from(real: T) -> DualNumber<T> {
+        DualNumber::from_real(real)
+    }
+}
+
+/// Conversion into a dual number with method syntax.
+///
+/// A `(real, dual)` tuple keeps both parts, and a bare number becomes a constant with a zero dual part.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, IntoDual};
+/// let x: DualNumber<f64> = (3.0, 1.0).into_dual();
+/// assert_eq!(x.into_tuple(), (3.0, 1.0));
+///
+/// let c = 3.0f64.into_dual();
+/// assert_eq!(c.into_tuple(), (3.0, 0.0));
+/// assert_eq!((x * c).into_tuple(), (9.0, 3.0));
+///
+/// assert_eq!(2i32.into_dual().into_tuple(), (2, 0));
+/// ```
+pub trait IntoDual<T> {
+    /// Converts `self` into a dual number.
+    fn into_dual(self) -> DualNumber<T>;
+}
+
+impl<T> IntoDual<T> for (T, T) {
+    #[inline]
+    fn into_dual(self) -> DualNumber<T> {
+        DualNumber::new(self.0, self.1)
+    }
+}
+
+impl<T: Zero> IntoDual<T> for T {
+    #[inline]
+    fn into_dual(self) -> DualNumber<T> {
+        DualNumber::from_real(self)
+    }
+}
+
+/// Conversions from and to `(real, dual)` tuples and `[real, dual]` arrays.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let x = DualNumber::from((1.5f64, -2.0));
+/// assert_eq!(x.into_tuple(), (1.5, -2.0));
+/// assert_eq!(DualNumber::from([1.5f64, -2.0]).into_tuple(), (1.5, -2.0));
+///
+/// let t: (f64, f64) = x.into();
+/// let a: [f64; 2] = x.into();
+/// assert_eq!((t, a), ((1.5, -2.0), [1.5, -2.0]));
+/// assert_eq!(DualNumber::from(a).into_tuple(), t);
+///
+/// // A view of the parts as an array, aliasing the dual number
+/// let mut y = DualNumber::new(3.0f32, 4.0);
+/// assert_eq!(y.as_ref(), &[3.0, 4.0]);
+/// y.as_mut()[1] = -1.0;
+/// assert_eq!((y.real(), y.dual()), (3.0, -1.0));
+///
+/// // Usable wherever the parts are taken as an array
+/// fn norm<P: Into<[f64; 2]>>(p: P) -> f64 {
+///     let [a, b] = p.into();
+///     a.hypot(b)
+/// }
+/// assert_eq!(norm(DualNumber::new(3.0, 4.0)), 5.0);
+/// assert_eq!(norm([3.0, 4.0]), 5.0);
+/// ```
+impl<T> From<(T, T)> for DualNumber<T> {
+    #[inline]
+    fn from(parts: (T, T)) -> DualNumber<T> {
+        DualNumber::new(parts.0, parts.1)
+    }
+}
+
+impl<T> From<[T; 2]> for DualNumber<T> {
+    #[inline]
+    fn from(parts: [T; 2]) -> DualNumber<T> {
+        let [real, dual] = parts;
+
+        DualNumber::new(real, dual)
+    }
+}
+
+impl<T> From<DualNumber<T>> for (T, T) {
+    #[inline]
+    fn from(x: DualNumber<T>) -> (T, T) {
+        x.into_tuple()
+    }
+}
+
+impl<T> From<DualNumber<T>> for [T; 2] {
+    #[inline]
+    fn from(x: DualNumber<T>) -> [T; 2] {
+        [x.0, x.1]
+    }
+}
+
+impl<T> AsRef<[T; 2]> for DualNumber<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T; 2] {
+        // SAFETY: `DualNumber<T>` is `repr(C)` with two fields of type `T`, the layout of `[T; 2]`
+        unsafe { &*(self as *const DualNumber<T> as *const [T; 2]) }
+    }
+}
+
+impl<T> AsMut<[T; 2]> for DualNumber<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T; 2] {
+        // SAFETY: as for `as_ref`
+        unsafe { &mut *(self as *mut DualNumber<T> as *mut [T; 2]) }
+    }
+}
+
+impl<T: Copy> DualNumber<T> {
+    /// Returns the real part
+    #[inline(always)]
+    pub const fn real(&self) -> T { self.0 }
+
+    /// Returns the dual part
+    #[inline(always)]
+    pub const fn dual(&self) -> T { self.1 }
+
+    /// Returns the value of the function, an alias of `real` for reporting code.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let y = DualNumber::new(3.0f64, 1.0).powi(2);
+    ///
+    /// assert_eq!(y.value(), y.real());
+    /// assert_eq!((y.value(), y.slope()), (9.0, 6.0));
+    /// ```
+    #[inline(always)]
+    pub const fn value(&self) -> T { self.0 }
+
+    /// Returns the local slope, which is the derivative with respect to the seeded variable, an alias
+    /// of `dual` for reporting code.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let y = DualNumber::new(0.0f64, 1.0).sin();
+    ///
+    /// assert_eq!(y.slope(), y.dual());
+    /// assert_eq!(y.slope(), 1.0);
+    /// ```
+    #[inline(always)]
+    pub const fn slope(&self) -> T { self.1 }
+}
+
+impl<T: Num + Copy + NumCast> DualNumber<T> {
+    /// Raises `self` to an `i64` power, for exponents beyond the range of `powi`.
+    ///
+    /// The value is computed by repeated squaring, and the dual part is `n x^(n-1) x'`.
+    /// Each squaring rounds, so the relative error grows roughly like `|n| ε`, and `n` itself
+    /// is rounded when converted to `T` (beyond `2^53` for `f64`).
+    ///
+    /// Integer parts are supported as well, where the arithmetic is exact until it overflows, and negative
+    /// exponents divide one by `x^|n|` with the usual integer truncation.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(1.3f64, 0.5);
+    ///
+    /// for n in -10..=10 {
+    ///     let (a, b) = (x.powi64(n as i64), x.powi(n));
+    ///
+    ///     assert!((a.real() - b.real()).abs() <= 1e-14 * b.real().abs());
+    ///     assert!((a.dual() - b.dual()).abs() <= 1e-14 * b.dual().abs());
+    /// }
+    ///
+    /// // An exponent beyond i32, with a base close to one
+    /// let n = 5_000_000_000i64;
+    /// let x = DualNumber::new(1.0 + 1e-10, 1.0);
+    /// let y = x.powi64(n);
+    /// let reference = x.real().powf(n as f64);
+    ///
+    /// assert!((y.real() - reference).abs() < 1e-5 * reference);
+    /// assert!((y.dual() - n as f64 * reference / x.real()).abs() < 1e-5 * n as f64 * reference);
+    /// assert_eq!(x.powi64(-n).real(), 1.0 / y.real());
+    ///
+    /// // Integer parts
+    /// assert_eq!(DualNumber::new(3i64, 2).powi64(4).into_tuple(), (81, 216));
+    /// assert_eq!(DualNumber::new(2u64, 1).powi64(40).into_tuple(), (1 << 40, 40 << 39));
+    /// assert_eq!(DualNumber::new(1i32, 1).powi64(-3).into_tuple(), (1, -3));
+    /// ```
+    pub fn powi64(self, n: i64) -> Self {
+        let x = self.real();
+        let nf = <T as NumCast>::from(n).expect("Invalid value");
+
+        if n == 0 {
+            DualNumber::new(T::one(), T::zero())
+        } else if n > 0 {
+            let below = powu(x, n.unsigned_abs() - 1);
+
+            DualNumber::new(below * x, nf * below * self.dual())
+        } else {
+            let power = powu(x, n.unsigned_abs());
+
+            DualNumber::new(T::one() / power, nf * self.dual() / (power * x))
+        }
+    }
+}
+
+macro_rules! impl_const_constructors {
+    ($($t:ident),*) => {$(
+        impl DualNumber<$t> {
+            /// The dual number zero, `0 + 0ε`.
+            pub const ZERO: DualNumber<$t> = DualNumber(0.0, 0.0);
+
+            /// The dual number one, `1 + 0ε`.
+            pub const ONE: DualNumber<$t> = DualNumber(1.0, 0.0);
+
+            /// The dual unit, `0 + 1ε`.
+            pub const EPSILON: DualNumber<$t> = DualNumber(0.0, 1.0);
+
+            /// Like `from_real` and `constant`, but usable in constants, since `Zero::zero` is not a
+            /// `const fn`.
+            ///
+            /// `new` is already a `const fn` for any part type, so only this constructor needs a
+            /// concrete version.
+            ///
+            /// ```rust
+            /// # use dual_num::{DualNumberF32, DualNumberF64, Float};
+            /// const G: DualNumberF64 = DualNumberF64::from_real_const(9.80665);
+            /// const TABLE: [DualNumberF64; 3] = [
+            ///     DualNumberF64::from_real_const(0.5),
+            ///     DualNumberF64::new(2.0, 1.0),
+            ///     DualNumberF64::ONE,
+            /// ];
+            ///
+            /// let t = DualNumberF64::new(1.5, 1.0);
+            /// let fall = G * t * t * TABLE[0];
+            /// assert_eq!(fall.into_tuple(), (9.80665 * 1.5 * 1.5 * 0.5, 9.80665 * 1.5));
+            /// assert_eq!((TABLE[1] * TABLE[2]).into_tuple(), (2.0, 1.0));
+            /// assert_eq!(G.into_tuple(), DualNumberF64::constant(9.80665).into_tuple());
+            ///
+            /// const HALF_PI: DualNumberF32 = DualNumberF32::from_real_const(std::f32::consts::FRAC_PI_2);
+            /// assert_eq!(HALF_PI.sin().into_tuple(), (1.0, 0.0));
+            /// ```
+            #[inline]
+            pub const fn from_real_const(real: $t) -> DualNumber<$t> {
+                DualNumber(real, 0.0)
+            }
+        }
+    )*}
+}
+
+impl_const_constructors!(f32, f64);
+
+impl<T: Float> DualNumber<T> {
+    /// Creates a dual number like `new`, or returns `None` if either part is NaN or infinite.
+    ///
+    /// This is meant for ingesting external data, where a non-finite value would otherwise
+    /// propagate silently through every later operation.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// assert_eq!(DualNumber::try_new(1.5f64, -2.0).map(|x| x.into_tuple()), Some((1.5, -2.0)));
+    /// assert_eq!(DualNumber::try_new(0.0f32, 1e-40).map(|x| x.into_tuple()), Some((0.0, 1e-40)));
+    ///
+    /// assert!(DualNumber::try_new(f64::NAN, 1.0).is_none());
+    /// assert!(DualNumber::try_new(1.0, f64::NAN).is_none());
+    /// assert!(DualNumber::try_new(f64::INFINITY, 1.0).is_none());
+    /// assert!(DualNumber::try_new(1.0, f64::NEG_INFINITY).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(real: T, dual: T) -> Option<Self> {
+        if real.is_finite() && dual.is_finite() {
+            Some(DualNumber(real, dual))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the real part if the magnitude of the dual part is at most `tol`, or the dual number
+    /// itself otherwise.
+    ///
+    /// This is for API boundaries that take plain values and must not silently drop a derivative.
+    /// A NaN dual part is never within the tolerance.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// assert_eq!(DualNumber::new(2.0f64, 0.0).try_into_real(0.0), Ok(2.0));
+    /// assert_eq!(DualNumber::new(2.0f64, -1e-9).try_into_real(1e-9), Ok(2.0));
+    /// assert_eq!(DualNumber::new(2.0f64, 1e-9).try_into_real(1e-9), Ok(2.0));
+    ///
+    /// let err = DualNumber::new(2.0f64, 1.0000001e-9).try_into_real(1e-9).unwrap_err();
+    /// assert_eq!(err.into_tuple(), (2.0, 1.0000001e-9));
+    ///
+    /// assert!(DualNumber::new(2.0f64, f64::NAN).try_into_real(f64::INFINITY).is_err());
+    /// assert!(DualNumber::new(2.0f64, f64::INFINITY).try_into_real(1e300).is_err());
+    /// ```
+    #[inline]
+    pub fn try_into_real(self, tol: T) -> Result<T, Self> {
+        if self.dual().abs() <= tol {
+            Ok(self.real())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Compares the real parts with the IEEE 754 total order, like `f64::total_cmp`.
+    ///
+    /// Unlike `partial_cmp` this always gives an ordering, so it can be passed directly to
+    /// `sort_by`. Negative NaNs come before every number and positive NaNs after, and `-0.0` is less
+    /// than `0.0`. The dual parts are ignored.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x = |re: f64| DualNumber::new(re, -re);
+    /// let mut xs = vec![x(f64::NAN), x(1.0), x(-0.0), x(f64::NEG_INFINITY), x(-f64::NAN), x(0.0), x(-2.5), x(f64::INFINITY)];
+    /// xs.sort_by(|a, b| a.cmp_real(b));
+    ///
+    /// let reals: Vec<_> = xs.iter().map(|x| x.real().to_bits()).collect();
+    /// let mut expected: Vec<_> = [f64::NAN, 1.0, -0.0, f64::NEG_INFINITY, -f64::NAN, 0.0, -2.5, f64::INFINITY].to_vec();
+    /// expected.sort_by(|a, b| a.total_cmp(b));
+    /// assert_eq!(reals, expected.iter().map(|x| x.to_bits()).collect::<Vec<_>>());
+    /// assert!(xs[0].real().is_nan() && xs[0].real().is_sign_negative() && xs[7].real().is_nan());
+    ///
+    /// // Agrees with total_cmp for every pair, NaN payloads included
+    /// let specials = [f64::NAN, -f64::NAN, f64::from_bits(0x7ff0_0000_0000_0001), f64::from_bits(0xfff8_0000_0000_0002),
+    ///                 f64::INFINITY, f64::NEG_INFINITY, 0.0, -0.0, 5e-324, -5e-324, 1.0, -1.0, f64::MAX];
+    /// for &a in &specials {
+    ///     for &b in &specials {
+    ///         assert_eq!(DualNumber::new(a, 1.0).cmp_real(&DualNumber::new(b, 0.0)), a.total_cmp(&b));
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(DualNumber::new(1.0f32, 5.0).cmp_real(&DualNumber::new(1.0, -5.0)), Ordering::Equal);
+    /// ```
+    pub fn cmp_real(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.real(), other.real());
+
+        match a.partial_cmp(&b) {
+            Some(Ordering::Equal) => a.is_sign_positive().cmp(&b.is_sign_positive()),
+            Some(ord) => ord,
+            None if a.is_sign_negative() != b.is_sign_negative() => a.is_sign_positive().cmp(&b.is_sign_positive()),
+            None => {
+                // At least one NaN and the same sign: NaNs are the largest magnitudes, then ordered by payload
+                let ord = a.is_nan().cmp(&b.is_nan())
+                    .then_with(|| a.integer_decode().0.cmp(&b.integer_decode().0));
+
+                if a.is_sign_negative() { ord.reverse() } else { ord }
+            }
+        }
+    }
+
+    /// Scales the dual part by `k`, keeping the real part.
+    ///
+    /// This rescales the derivative without changing the value, as when changing the units of the
+    /// seeded variable or perturbing a sensitivity.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let x = DualNumber::new(2.5f64, -0.75);
+    ///
+    /// assert_eq!(x.scale_dual(-1.0).into_tuple(), x.conjugate().into_tuple());
+    /// assert_eq!(x.scale_dual(2.0).into_tuple(), (2.5, -1.5));
+    /// assert_eq!(x.scale_dual(0.0).into_tuple(), (2.5, -0.0));
+    /// ```
+    #[inline]
+    pub fn scale_dual(self, k: T) -> Self {
+        DualNumber(self.real(), self.dual() * k)
+    }
+
+    /// Clamps the dual part to `[-max_abs, max_abs]`, keeping the real part, like per-value gradient clipping.
+    ///
+    /// This deliberately breaks exact differentiation: the result is no longer the derivative of
+    /// anything once the dual part has been clipped, and the clipping propagates through any later
+    /// operation. A NaN dual part is kept as is, and `max_abs` should not be negative.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let x = DualNumber::new(3.0f64, 0.5);
+    /// assert_eq!(x.clamp_dual(1.0).into_tuple(), (3.0, 0.5));
+    /// assert_eq!(x.clamp_dual(0.5).into_tuple(), (3.0, 0.5));
+    ///
+    /// assert_eq!(DualNumber::new(3.0f64, 20.0).clamp_dual(1.0).into_tuple(), (3.0, 1.0));
+    /// assert_eq!(DualNumber::new(-3.0f64, -20.0).clamp_dual(1.0).into_tuple(), (-3.0, -1.0));
+    /// assert_eq!(DualNumber::new(1.0f64, f64::NEG_INFINITY).clamp_dual(2.5).into_tuple(), (1.0, -2.5));
+    /// assert_eq!(DualNumber::new(1.0f64, 4.0).clamp_dual(0.0).into_tuple(), (1.0, 0.0));
+    /// assert!(DualNumber::new(1.0f64, f64::NAN).clamp_dual(1.0).dual().is_nan());
+    /// ```
+    #[inline]
+    pub fn clamp_dual(self, max_abs: T) -> Self {
+        let dual = if self.dual() > max_abs {
+            max_abs
+        } else if self.dual() < max_abs.neg() {
+            max_abs.neg()
+        } else {
+            self.dual()
+        };
+
+        DualNumber(self.real(), dual)
+    }
+
+    /// Returns `true` if the dual part has a negative sign, including `-0.0` and NaNs with the sign bit set.
+    ///
+    /// `Float::is_sign_negative` only looks at the real part, which is the sign of the value itself and
+    /// what comparisons and branches should use. This instead gives the direction in which the value is
+    /// moving, which is useful at a zero real part, for example to pick the side of a branch cut the
+    /// derivative approaches from, or when a derivative has underflowed to a signed zero.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.0f64, -0.0);
+    ///
+    /// assert!(x.is_sign_positive() && !x.is_sign_negative());
+    /// assert!(x.dual_is_sign_negative() && !x.dual_is_sign_positive());
+    ///
+    /// let y = DualNumber::new(-0.0f64, 0.0);
+    /// assert!(y.is_sign_negative() && y.dual_is_sign_positive());
+    ///
+    /// assert!(DualNumber::new(1.0f64, -2.0).dual_is_sign_negative());
+    /// assert!(DualNumber::new(-1.0f64, -f64::NAN).dual_is_sign_negative());
+    /// assert!(DualNumber::new(-1.0f64, f64::INFINITY).dual_is_sign_positive());
+    ///
+    /// // Negating a constant gives a negative zero derivative
+    /// assert!((-DualNumber::from_real(1.0f64)).dual_is_sign_negative());
+    /// ```
+    #[inline]
+    pub fn dual_is_sign_negative(&self) -> bool {
+        self.dual().is_sign_negative()
+    }
+
+    /// Returns `true` if the dual part has a positive sign, including `+0.0` and NaNs without the sign bit.
+    ///
+    /// See `dual_is_sign_negative` for when to use this instead of `Float::is_sign_positive`.
+    #[inline]
+    pub fn dual_is_sign_positive(&self) -> bool {
+        self.dual().is_sign_positive()
+    }
+
+    /// Returns a dual number with the magnitude of `self` and the sign of the scalar `sign`.
+    ///
+    /// The dual part is negated if and only if the sign of the real part was flipped,
+    /// so the result is locally `±self`. Signed zeros and NaNs are handled exactly like
+    /// the primitive `copysign`, by looking at the sign bit of `sign`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(2.0f64, 1.0);
+    ///
+    /// assert_eq!(x.copysign_scalar(3.0).into_tuple(), (2.0, 1.0));
+    /// assert_eq!(x.copysign_scalar(-3.0).into_tuple(), (-2.0, -1.0));
+    /// assert_eq!((-x).copysign_scalar(3.0).into_tuple(), (2.0, 1.0));
+    /// assert_eq!((-x).copysign_scalar(-3.0).into_tuple(), (-2.0, -1.0));
+    ///
+    /// assert_eq!(x.copysign_scalar(0.0).into_tuple(), (2.0, 1.0));
+    /// assert_eq!(x.copysign_scalar(-0.0).into_tuple(), (-2.0, -1.0));
+    /// assert_eq!(x.copysign_scalar(f64::NAN).into_tuple(), (2.0, 1.0));
+    /// assert_eq!(x.copysign_scalar(-f64::NAN).into_tuple(), (-2.0, -1.0));
+    ///
+    /// // The dual version only looks at the real part of the sign
+    /// assert_eq!(x.copysign(DualNumber::new(-1.0, 5.0)).into_tuple(), (-2.0, -1.0));
+    /// ```
+    pub fn copysign_scalar(self, sign: T) -> Self {
+        if self.real().is_sign_negative() == sign.is_sign_negative() {
+            self
+        } else {
+            DualNumber(self.real().neg(), self.dual().neg())
+        }
+    }
+
+    /// Returns `self` with its magnitude kept and the sign of the real part of `other`,
+    /// like `abs().copysign(other)`.
+    ///
+    /// The dual part follows the real part exactly as in `copysign_scalar`.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let x = DualNumber::new(2.0f64, 1.0);
+    /// let pos = DualNumber::new(0.5f64, -4.0);
+    /// let neg = DualNumber::new(-0.5f64, -4.0);
+    ///
+    /// // Sign preserved
+    /// assert_eq!(x.with_sign_of(pos).into_tuple(), (2.0, 1.0));
+    /// assert_eq!((-x).with_sign_of(neg).into_tuple(), (-2.0, -1.0));
+    ///
+    /// // Sign flipped, along with the dual part
+    /// assert_eq!(x.with_sign_of(neg).into_tuple(), (-2.0, -1.0));
+    /// assert_eq!((-x).with_sign_of(pos).into_tuple(), (2.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn with_sign_of(self, other: Self) -> Self {
+        self.copysign_scalar(other.real())
+    }
+
+    /// Clamps the real part to the scalar range `[lo, hi]`.
+    ///
+    /// Where the result is pinned to one of the bounds the dual part is zero,
+    /// otherwise the dual number is returned unchanged.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let x = |x: f64| DualNumber::new(x, 2.0);
+    ///
+    /// assert_eq!(x(-5.0).clamp_scalar(-1.0, 1.0).into_tuple(), (-1.0, 0.0));
+    /// assert_eq!(x(0.5).clamp_scalar(-1.0, 1.0).into_tuple(), (0.5, 2.0));
+    /// assert_eq!(x(5.0).clamp_scalar(-1.0, 1.0).into_tuple(), (1.0, 0.0));
+    /// ```
+    pub fn clamp_scalar(self, lo: T, hi: T) -> Self {
+        if self.real() < lo {
+            DualNumber::from_real(lo)
+        } else if self.real() > hi {
+            DualNumber::from_real(hi)
+        } else {
+            self
+        }
+    }
+
+    /// Wraps the real part, as an angle in radians, to `[0, 2π)`.
+    ///
+    /// Since wrapping is locally the identity, the dual part passes through unchanged,
+    /// including at the seam itself. Use `floor` and friends instead if a zero derivative is wanted there.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// let tau = 2.0 * f64::PI();
+    ///
+    /// assert_eq!(DualNumber::new(tau - 0.5, 1.0).wrap_to_2pi().into_tuple(), (tau - 0.5, 1.0));
+    /// assert!((DualNumber::new(tau + 0.5, 1.0).wrap_to_2pi().real() - 0.5).abs() < 1e-15);
+    /// assert!((DualNumber::new(-0.5, 1.0).wrap_to_2pi().real() - (tau - 0.5)).abs() < 1e-15);
+    ///
+    /// let big = DualNumber::new(1e10f64, 1.0);
+    /// let w = big.wrap_to_2pi();
+    ///
+    /// assert!(w.real() >= 0.0 && w.real() < tau);
+    /// assert_eq!(w.dual(), 1.0);
+    /// assert!((w.sin().real() - big.sin().real()).abs() < 1e-6);
+    /// assert!((w.sin().dual() - big.sin().dual()).abs() < 1e-6);
+    /// ```
+    pub fn wrap_to_2pi(self) -> Self where T: FloatConst {
+        let tau = T::PI() + T::PI();
+
+        let mut real = self.real() % tau;
+
+        if real < T::zero() {
+            real = real + tau;
+        }
+
+        // Tiny negative remainders may round up to exactly 2π
+        if real >= tau {
+            real = T::zero();
+        }
+
+        DualNumber::new(real, self.dual())
+    }
+
+    /// Wraps the real part, as an angle in radians, to `[-π, π]`.
+    ///
+    /// Angles already in the range are returned unchanged, including both ends, and the others land in
+    /// `[-π, π)`. As with `wrap_to_2pi`, the dual part passes through unchanged.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// let pi = f64::PI();
+    ///
+    /// assert!((DualNumber::new(pi - 0.5, 1.0).wrap_to_pi().real() - (pi - 0.5)).abs() < 1e-15);
+    /// assert!((DualNumber::new(pi + 0.5, 1.0).wrap_to_pi().real() - (0.5 - pi)).abs() < 1e-15);
+    /// assert!((DualNumber::new(-7.0, 1.0).wrap_to_pi().real() - (2.0 * pi - 7.0)).abs() < 1e-15);
+    ///
+    /// // Both ends of the range are kept
+    /// assert_eq!(DualNumber::new(pi, 1.0).wrap_to_pi().into_tuple(), (pi, 1.0));
+    /// assert_eq!(DualNumber::new(-pi, 1.0).wrap_to_pi().into_tuple(), (-pi, 1.0));
+    ///
+    /// let x = DualNumber::new(pi + 0.5, 1.0);
+    /// let w = x.wrap_to_pi();
+    ///
+    /// assert_eq!(w.dual(), 1.0);
+    /// assert!((w.cos().real() - x.cos().real()).abs() < 1e-15);
+    /// assert!((w.cos().dual() - x.cos().dual()).abs() < 1e-15);
+    /// ```
+    pub fn wrap_to_pi(self) -> Self where T: FloatConst {
+        if self.real().abs() <= T::PI() {
+            return self;
+        }
+
+        let shifted = DualNumber::new(self.real() + T::PI(), self.dual()).wrap_to_2pi();
+
+        DualNumber::new(shifted.real() - T::PI(), shifted.dual())
+    }
+
+    /// Multiplies both parts by `2^exp`, rounding once like multiplying by a representable power of two.
+    ///
+    /// This is exact unless a part overflows or becomes subnormal, where it is correctly rounded.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let x = DualNumber::new(0.75f64, -3.0);
+    ///
+    /// assert_eq!(x.ldexp(4).into_tuple(), (12.0, -48.0));
+    /// assert_eq!(x.ldexp(-2).into_tuple(), (0.1875, -0.75));
+    /// assert_eq!(DualNumber::new(1.0f64, 1.0).ldexp(-1074).real(), 5e-324);
+    ///
+    /// // Subnormal results are rounded once, the same as a product with the power of two
+    /// for &v in &[1.0f64 + 3.0 * f64::EPSILON, 1.7, -1.999_999_999_999_999_8, 1.5e10, 3.3e-200] {
+    ///     for e in -1074..-1022 {
+    ///         let pow2 = f64::from_bits(1 << (e + 1074));
+    ///         let scale = (v.abs().log2().floor() as i32).max(0);
+    ///
+    ///         let y = DualNumber::new(v, -v).ldexp(e - scale);
+    ///         let expected = v * DualNumber::new(1.0f64, 1.0).ldexp(-scale).real() * pow2;
+    ///         assert_eq!(y.real().to_bits(), expected.to_bits());
+    ///         assert_eq!(y.dual().to_bits(), (-expected).to_bits());
+    ///     }
+    /// }
+    /// assert_eq!(DualNumber::new(3.0f64, 1.0).ldexp(-1075).real(), 1e-323);
+    ///
+    /// // Exponents far out of range saturate quickly
+    /// assert_eq!(DualNumber::new(1.0f64, -3.0).ldexp(i32::MIN).into_tuple(), (0.0, -0.0));
+    /// assert_eq!(DualNumber::new(1.0f64, -3.0).ldexp(i32::MAX).into_tuple(), (f64::INFINITY, f64::NEG_INFINITY));
+    /// assert_eq!(DualNumber::new(f64::MAX, 5e-324).ldexp(-2098).into_tuple(), (5e-324, 0.0));
+    /// ```
+    pub fn ldexp(self, exp: i32) -> Self {
+        DualNumber(ldexp(self.real(), exp), ldexp(self.dual(), exp))
+    }
+
+    /// Splits the real part into a mantissa in `[0.5, 1)` (in magnitude) and a power of two.
+    ///
+    /// Returns `(m, e)` such that `self == m.ldexp(e)`. The exponent is taken from the real part only,
+    /// and the dual part of the mantissa is the dual part of `self` scaled by the same `2^-e`.
+    /// Zero, infinite and NaN real parts are returned unchanged with an exponent of zero.
+    ///
+    /// Note that the scaled dual part may overflow if it is many orders of magnitude larger than the real part.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let x = DualNumber::new(-12.0f64, 3.0);
+    /// let (m, e) = x.frexp();
+    ///
+    /// assert_eq!((m.into_tuple(), e), ((-0.75, 0.1875), 4));
+    /// assert_eq!(m.ldexp(e).into_tuple(), x.into_tuple());
+    ///
+    /// for &v in &[1.0f64, 0.5, 3.0e-310, 1.7e308, -1e-5] {
+    ///     let (m, e) = DualNumber::new(v, -v / 4.0).frexp();
+    ///
+    ///     assert!(m.real().abs() >= 0.5 && m.real().abs() < 1.0);
+    ///     assert_eq!(m.ldexp(e).into_tuple(), (v, -v / 4.0));
+    /// }
+    /// ```
+    pub fn frexp(self) -> (Self, i32) {
+        let x = self.real();
+
+        if x.is_zero() || !x.is_finite() {
+            return (self, 0);
+        }
+
+        let (mantissa, exponent, _) = x.integer_decode();
+        let e = exponent as i32 + (64 - mantissa.leading_zeros()) as i32;
+
+        (self.ldexp(-e), e)
+    }
+
+    /// Splits into integer and fractional parts, returning `(trunc, fract)`.
+    ///
+    /// As with `trunc` and `fract`, the integer part has a zero dual part and the
+    /// fractional part keeps the whole derivative, so that `int + frac == self` in both parts.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let (int, frac) = DualNumber::new(-3.25f64, 2.0).modf();
+    ///
+    /// assert_eq!(int.into_tuple(), (-3.0, 0.0));
+    /// assert_eq!(frac.into_tuple(), (-0.25, 2.0));
+    /// assert_eq!((int + frac).into_tuple(), (-3.25, 2.0));
+    /// ```
+    pub fn modf(self) -> (Self, Self) {
+        (DualNumber::from_real(self.real().trunc()), DualNumber(self.real().fract(), self.dual()))
+    }
+
+    /// Rounds the real part to the nearest integer, with ties going to the even integer.
+    ///
+    /// As with `round`, the dual part is zero.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let r = |x: f64| DualNumber::new(x, 1.0).round_ties_even().into_tuple();
+    ///
+    /// assert_eq!(r(0.5), (0.0, 0.0));
+    /// assert_eq!(r(1.5), (2.0, 0.0));
+    /// assert_eq!(r(2.5), (2.0, 0.0));
+    /// assert_eq!(r(-0.5), (0.0, 0.0));
+    /// assert_eq!(r(-1.5), (-2.0, 0.0));
+    /// assert_eq!(r(2.6), (3.0, 0.0));
+    /// ```
+    pub fn round_ties_even(self) -> Self {
+        let x = self.real();
+        let mut r = x.round();
+
+        if (r - x).abs() == T::from(0.5).unwrap() {
+            r = (x / T::from(2).unwrap()).round() * T::from(2).unwrap();
+        }
+
+        DualNumber::from_real(r)
+    }
+
+    /// Returns the midpoint `(self + other) / 2` in both parts, without overflowing for huge values.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let a = DualNumber::new(1.0f64, 3.0);
+    /// let b = DualNumber::new(4.0f64, -1.0);
+    ///
+    /// assert_eq!(a.midpoint(b).into_tuple(), ((a + b) / 2.0).into_tuple());
+    /// assert_eq!(a.midpoint(b).dual(), 1.0);
+    ///
+    /// let max = DualNumber::new(f64::MAX, f64::MAX);
+    /// assert_eq!(max.midpoint(max).into_tuple(), (f64::MAX, f64::MAX));
+    /// assert_eq!(max.midpoint(-max).into_tuple(), (0.0, 0.0));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        DualNumber(midpoint(self.real(), other.real()), midpoint(self.dual(), other.dual()))
+    }
+
+    /// Computes the real `n`-th root, with derivative `(1/n) x^(1/n - 1) x'`.
+    ///
+    /// Unlike `powf(1/n)`, odd roots of negative numbers are the negative real root, as with `cbrt`.
+    /// Even roots of negative numbers are NaN, and `n = 0` gives NaN. Roots of zero have an infinite
+    /// dual part for `n > 1`, with the sign of `x'`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// assert!(DualNumber::new(-8.0f64, 1.0).powf(DualNumber::from_real(1.0 / 3.0)).real().is_nan());
+    /// assert_eq!(DualNumber::new(-8.0f64, 1.0).nth_root(3).into_tuple(), (-2.0, 1.0 / 12.0));
+    ///
+    /// // Odd roots of negatives mirror the positive ones
+    /// for &n in &[1u32, 3, 5, 7, 21] {
+    ///     for &x in &[0.3f64, 2.0, 1e10] {
+    ///         let (pos, neg) = (DualNumber::new(x, 1.0).nth_root(n), DualNumber::new(-x, 1.0).nth_root(n));
+    ///         let root = x.powf(1.0 / n as f64);
+    ///
+    ///         assert!((pos.real() - root).abs() <= 1e-15 * root);
+    ///         assert!((pos.dual() - root / (n as f64 * x)).abs() <= 1e-15 * root / x);
+    ///         assert_eq!(neg.into_tuple(), (-pos.real(), pos.dual()));
+    ///     }
+    /// }
+    ///
+    /// // Even roots
+    /// assert_eq!(DualNumber::new(16.0f64, 1.0).nth_root(4).into_tuple(), (2.0, 1.0 / 32.0));
+    /// assert_eq!(DualNumber::new(9.0f64, 1.0).nth_root(2).into_tuple(), (3.0, 1.0 / 6.0));
+    /// assert!(DualNumber::new(-16.0f64, 1.0).nth_root(4).real().is_nan());
+    /// assert!(DualNumber::new(2.0f64, 1.0).nth_root(0).real().is_nan());
+    ///
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).nth_root(5).into_tuple(), (0.0, f64::INFINITY));
+    /// assert_eq!(DualNumber::new(-0.0f64, -1.0).nth_root(3).into_tuple(), (-0.0, f64::NEG_INFINITY));
+    ///
+    /// // High orders and extreme magnitudes keep a finite, accurate slope
+    /// for &(x, n) in &[(1e300f64, 1000u32), (f64::MAX, 64), (5e-324, 9), (1e-300, 4096)] {
+    ///     let r = DualNumber::new(x, 1.0).nth_root(n);
+    ///     let slope = x.powf(1.0 / n as f64 - 1.0) / n as f64;
+    ///
+    ///     assert!(r.dual().is_finite() && (r.dual() - slope).abs() <= 1e-12 * slope);
+    /// }
+    /// ```
+    pub fn nth_root(self, n: u32) -> Self {
+        let x = self.real();
+
+        let real = match n {
+            0 => return DualNumber::new(T::nan(), T::nan()),
+            1 => return self,
+            2 => x.sqrt(),
+            3 => x.cbrt(),
+            _ if n & 1 == 0 => x.powf(T::from(n).unwrap().recip()),
+            _ => x.abs().powf(T::from(n).unwrap().recip()).copysign(x),
+        };
+
+        // (1/n) x^(1/n - 1) = root / (n x), which is an infinite slope at zero
+        let slope = if x.is_zero() {
+            T::zero().recip()
+        } else {
+            real / x / T::from(n).unwrap()
+        };
+
+        DualNumber::new(real, self.dual() * slope)
+    }
+
+    /// Base-10 exponential, with derivative `dual · ln(10) · 10^real`.
+    ///
+    /// Like `exp` and `exp2`, the derivative at a real part of `-inf` is zero, and the dual part of
+    /// `10^inf` is `inf · dual`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let y = DualNumber::new(2.0f64, 0.5).exp10();
+    /// assert_eq!(y.real(), 100.0);
+    /// assert!((y.dual() - 50.0 * std::f64::consts::LN_10).abs() < 1e-13);
+    ///
+    /// assert_eq!(DualNumber::new(-3.0f64, 1.0).exp10().real(), 1e-3);
+    /// assert_eq!(DualNumber::new(f64::NEG_INFINITY, 1.0).exp10().into_tuple(), (0.0, 0.0));
+    /// assert_eq!(DualNumber::new(f64::INFINITY, 1.0).exp10().into_tuple(), (f64::INFINITY, f64::INFINITY));
+    /// ```
+    #[inline]
+    pub fn exp10(self) -> Self where T: FloatConst {
+        let real = T::from(10).unwrap().powf(self.real());
+
+        self.exp_parts(real, self.dual() * T::LN_10())
+    }
+
+    /// Builds the result of an exponential from its value and the dual part scaled by the log of its base.
+    ///
+    /// The dual part is `dual · real`, except at a real part of `-inf`, where the value is zero and the
+    /// derivative is the limit zero, rather than the NaN of `0 · inf` for an infinite dual part.
+    #[inline]
+    fn exp_parts(self, real: T, dual: T) -> Self {
+        if self.real() == T::neg_infinity() {
+            DualNumber::new(real, T::zero() * dual.signum())
+        } else {
+            DualNumber::new(real, dual * real)
+        }
+    }
+
+    /// Builds the result of `sqrt` from its value, with the dual part `dual / (2 real)`.
+    #[inline]
+    fn sqrt_parts(self, real: T) -> Self {
+        let two = T::one() + T::one();
+
+        DualNumber::new(real, self.dual() / (two * real))
+    }
+
+    /// Builds the result of `ln` from its value, with the dual part `dual / x`.
+    #[inline]
+    fn ln_parts(self, real: T) -> Self {
+        DualNumber::new(real, self.dual() / self.real())
+    }
+
+    /// Builds the results of `sin` and `cos` from their values, with the dual parts `dual cos` and `-dual sin`.
+    #[inline]
+    fn sin_cos_parts(self, sin: T, cos: T) -> (Self, Self) {
+        (DualNumber::new(sin, self.dual() * cos), DualNumber::new(cos, self.dual().neg() * sin))
+    }
+
+    /// Builds the result of `tanh` from its value, with the dual part `dual (1 - real²)`.
+    #[inline]
+    fn tanh_parts(self, real: T) -> Self {
+        DualNumber::new(real, self.dual() * (T::one() - real * real))
+    }
+}
+
+/// Computes the dual part of the quotient `a / b` with the quotient rule, `(a'b - ab')/b²`.
+#[inline]
+fn quotient_dual<T: Num + Clone>(a: T, da: T, b: T, db: T) -> T {
+    (da * b.clone() - a * db) / (b.clone() * b)
+}
+
+/// Computes `√(1 - x²)`, the denominator of the derivatives of `asin` and `acos`.
+#[inline]
+fn one_minus_sq_sqrt<T: Float>(x: T) -> T {
+    (T::one() - x * x).sqrt()
+}
+
+/// Computes `x^n` by repeated squaring, without squaring past the last bit so integers only overflow
+/// when the result does.
+fn powu<T: Num + Copy>(x: T, n: u64) -> T {
+    let mut result = T::one();
+    let mut base = x;
+    let mut n = n;
+
+    while n > 0 {
+        if n & 1 == 1 {
+            result = result * base;
+        }
+
+        n >>= 1;
+
+        if n > 0 {
+            base = base * base;
+        }
+    }
+
+    result
+}
+
+/// Computes `(a + b) / 2`, halving first when the sum could overflow.
+fn midpoint<T: Float>(a: T, b: T) -> T {
+    let two = T::from(2).unwrap();
+    let limit = T::max_value() / two;
+
+    if a.abs() <= limit && b.abs() <= limit {
+        (a + b) / two
+    } else {
+        a / two + b / two
+    }
+}
+
+/// Computes `x * 2^exp`, scaling in steps small enough to be representable by any float type.
+///
+/// The exponent is first clamped to the widest range that can still change the result. Steps down are
+/// only taken while `x` stays normal, so that a subnormal result is rounded by the last multiplication only.
+fn ldexp<T: Float>(x: T, exp: i32) -> T {
+    const STEP: i32 = 60;
+
+    let two = T::one() + T::one();
+    let up = two.powi(STEP);
+    let down = up.recip();
+
+    // The largest power of two, the lowest bit of the smallest subnormal and the mantissa digits
+    let (mantissa, max_exp, _) = T::max_value().integer_decode();
+    let digits = (64 - mantissa.leading_zeros()) as i32;
+    let (_, min_exp, _) = T::min_positive_value().integer_decode();
+    let limit = (max_exp as i32 + digits) - min_exp as i32 + digits;
+
+    let mut x = x;
+    let mut exp = exp.max(-limit).min(limit);
+
+    while exp > STEP {
+        x = x * up;
+        exp -= STEP;
+    }
+
+    while exp < -STEP && (x * down).abs() >= T::min_positive_value() {
+        x = x * down;
+        exp += STEP;
+    }
+
+    // `2^exp` in two halves, either of which stays normal while their product can be subnormal
+    x * (two.powi(exp / 2) * two.powi(exp - exp / 2))
+}
+
+/// Formats an integer in the given radix, using lowercase letters for digits above 9.
+fn int_to_string_radix<T: PrimInt>(n: T, radix: u32) -> String {
+    assert!((2..=36).contains(&radix), "radix must be in the range 2..=36, got {}", radix);
+
+    let base = T::from(radix).expect("radix does not fit in the integer type");
+
+    if n.is_zero() {
+        return "0".to_owned();
+    }
+
+    let mut digits = Vec::new();
+    let mut rest = n;
+
+    // Digits are taken from the (non-positive) remainders of negative numbers directly,
+    // so that the minimum value of signed types doesn't overflow on negation
+    while !rest.is_zero() {
+        let rem = rest % base;
+        let digit = if rem < T::zero() { T::zero() - rem } else { rem };
+
+        digits.push(std::char::from_digit(digit.to_u32().unwrap(), radix).unwrap());
+        rest = rest / base;
+    }
+
+    if n < T::zero() {
+        digits.push('-');
+    }
+
+    digits.iter().rev().collect()
+}
+
+impl<T: PrimInt> DualNumber<T> {
+    /// Formats an integer dual number as `<real>+e<dual>`, with both parts in the given radix.
+    ///
+    /// The result can be parsed back with `from_str_radix_dual`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let x = DualNumber::new(5i32, -3);
+    ///
+    /// assert_eq!(x.to_string_radix(2), "101+e-11");
+    /// assert_eq!(DualNumber::new(255u8, 30).to_string_radix(16), "ff+e1e");
+    /// assert_eq!(DualNumber::new(i32::min_value(), 0).to_string_radix(16), "-80000000+e0");
+    ///
+    /// let y = DualNumber::from_str_radix_dual(&x.to_string_radix(16), 16).unwrap();
+    /// assert_eq!(y.into_tuple(), (5, -3));
+    /// ```
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        format!("{}+e{}", int_to_string_radix(self.real(), radix), int_to_string_radix(self.dual(), radix))
+    }
+
+    /// Parses an integer dual number of the form `<real>+e<dual>` in the given radix,
+    /// as produced by `to_string_radix`.
+    ///
+    /// A string without the `+e` separator is parsed as a real number with a zero dual part.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// assert_eq!(DualNumber::<i32>::from_str_radix_dual("-101+e11", 2).unwrap().into_tuple(), (-5, 3));
+    /// assert_eq!(DualNumber::<i32>::from_str_radix_dual("1e+eee", 16).unwrap().into_tuple(), (30, 238));
+    /// assert_eq!(DualNumber::<i32>::from_str_radix_dual("z", 36).unwrap().into_tuple(), (35, 0));
+    /// assert!(DualNumber::<i32>::from_str_radix_dual("12+e3", 2).is_err());
+    /// ```
+    pub fn from_str_radix_dual(s: &str, radix: u32) -> Result<Self, T::FromStrRadixErr> {
+        match s.find("+e") {
+            Some(i) => Ok(DualNumber::new(T::from_str_radix(&s[..i], radix)?,
+                                          T::from_str_radix(&s[i + 2..], radix)?)),
+            None => T::from_str_radix(s, radix).map(DualNumber::from_real),
+        }
+    }
+}
+
+/// Debug formatting with named parts, `DualNumber { re: 3.0, du: 1.0 }`.
+///
+/// Each part is printed with its own `Debug`, and `{:#?}` spreads the parts over separate lines
+/// like any other struct.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// assert_eq!(format!("{:?}", DualNumber::new(3.0f64, 1.0)), "DualNumber { re: 3.0, du: 1.0 }");
+/// assert_eq!(format!("{:?}", DualNumber::new(-0.0f32, f32::NAN)), "DualNumber { re: -0.0, du: NaN }");
+/// assert_eq!(format!("{:.1?}", DualNumber::new(0.25f64, 2.0)), "DualNumber { re: 0.2, du: 2.0 }");
+///
+/// let nested = DualNumber::new(DualNumber::new(1.0f64, 2.0), DualNumber::new(3.0, 4.0));
+/// assert_eq!(format!("{:?}", nested),
+///            "DualNumber { re: DualNumber { re: 1.0, du: 2.0 }, du: DualNumber { re: 3.0, du: 4.0 } }");
+///
+/// let text = DualNumber::new(String::from("x"), String::from("dx"));
+/// assert_eq!(format!("{:?}", text), r#"DualNumber { re: "x", du: "dx" }"#);
+///
+/// assert_eq!(format!("{:#?}", DualNumber::new(3.0f64, 1.0)), "DualNumber {\n    re: 3.0,\n    du: 1.0,\n}");
+/// ```
+impl<T: Debug> Debug for DualNumber<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("DualNumber").field("re", &self.0).field("du", &self.1).finish()
+    }
+}
+
+/// Display as `real + εdual`, or with `{:#}` as a plain `(real, dual)` pair.
+///
+/// Both forms print each part with the formatter's precision, two decimals by default. The alternate
+/// form can be pasted into Python or gnuplot, and is read back by `FromStr`.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let x = DualNumber::new(3.0f64, 1.0);
+///
+/// assert_eq!(format!("{}", x), "3.00 + ε1.00");
+/// assert_eq!(format!("{:#}", x), "(3.00, 1.00)");
+/// assert_eq!(format!("{:#.4}", x), "(3.0000, 1.0000)");
+/// assert_eq!(format!("{:#.0}", DualNumber::new(-2.5f64, -0.4)), "(-2, -0)");
+/// assert_eq!(format!("{:#}", DualNumber::new(f64::NEG_INFINITY, f64::NAN)), "(-inf, NaN)");
+/// assert_eq!(format!("{:#}", DualNumber::new(7, -1)), "(7, -1)");
+/// ```
+impl<T: Display> Display for DualNumber<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let precision = f.precision().unwrap_or(2);
+
+        if f.alternate() {
+            write!(f, "({:.p$}, {:.p$})", self.0, self.1, p = precision)
+        } else {
+            write!(f, "{:.p$} + \u{03B5}{:.p$}", self.0, self.1, p = precision)
+        }
+    }
+}
+
+/// Parses either display form, `real + εdual` or `(real, dual)`, or a bare real number with a zero
+/// dual part.
+///
+/// Whitespace around the parts is ignored. A malformed string is reported as the error of parsing
+/// it, or the offending part, as a `T`.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let parse = |s: &str| s.parse::<DualNumber<f64>>().map(|x| x.into_tuple());
+///
+/// assert_eq!(parse("(3.5, -1)"), Ok((3.5, -1.0)));
+/// assert_eq!(parse("3.5 + ε-1"), Ok((3.5, -1.0)));
+/// assert_eq!(parse(" ( 1e3 ,2 ) "), Ok((1000.0, 2.0)));
+/// assert_eq!(parse("-inf + εinf"), Ok((f64::NEG_INFINITY, f64::INFINITY)));
+/// assert_eq!(parse("0.25"), Ok((0.25, 0.0)));
+/// assert!(parse("(1, 2").is_err() && parse("(1 2)").is_err() && parse("1 - ε2").is_err());
+/// assert!(parse("(NaN, -NaN)").unwrap().0.is_nan());
+///
+/// // Both display forms round trip, with enough precision
+/// for &(re, du) in &[(3.0f64, 1.0), (-0.1, 2.5e-3), (1e-7, -4e12), (-0.0, 0.0), (f64::INFINITY, -1.0)] {
+///     let x = DualNumber::new(re, du);
+///
+///     for s in &[format!("{:#.17}", x), format!("{:.17}", x)] {
+///         let y: DualNumber<f64> = s.parse().unwrap();
+///         assert_eq!(y.into_tuple(), (re, du), "{}", s);
+///     }
+/// }
+/// assert_eq!(parse(&format!("{:#}", DualNumber::new(3.0f64, -1.25))), Ok((3.0, -1.25)));
+/// assert_eq!("(-4, 7)".parse::<DualNumber<i32>>().unwrap().into_tuple(), (-4, 7));
+/// ```
+impl<T: FromStr + Zero> FromStr for DualNumber<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, T::Err> {
+        let s = s.trim();
+
+        if let Some(pair) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            if let Some(i) = pair.find(',') {
+                return Ok(DualNumber::new(pair[..i].trim().parse()?, pair[i + 1..].trim().parse()?));
+            }
+        } else if let Some(i) = s.find('\u{03B5}') {
+            if let Some(real) = s[..i].trim_end().strip_suffix('+') {
+                return Ok(DualNumber::new(real.trim().parse()?, s[i + '\u{03B5}'.len_utf8()..].trim().parse()?));
+            }
+        }
+
+        s.parse().map(DualNumber::from_real)
+    }
+}
+
+/// Display of a dual number with a custom symbol for `ε`, created by `DualNumber::display_ascii`
+/// and `DualNumber::display_with`.
+///
+/// The dual number is printed as `real + dual*symbol`, with the formatter's precision (two decimals
+/// by default) like the `Display` of `DualNumber` itself.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let x = DualNumber::new(3.0f64, 1.0);
+///
+/// assert_eq!(format!("{}", x.display_ascii()), "3.00 + 1.00*eps");
+/// assert_eq!(format!("{}", DualNumber::new(-0.5f64, -2.25).display_ascii()), "-0.50 + -2.25*eps");
+/// assert_eq!(format!("{:.4}", x.display_ascii()), "3.0000 + 1.0000*eps");
+/// assert_eq!(format!("{:.0}", x.display_with("dx")), "3 + 1*dx");
+/// assert_eq!(format!("{}", x.display_with("∂ₓ")), "3.00 + 1.00*∂ₓ");
+///
+/// // The default display is unchanged, and references to the adapters display as well
+/// assert_eq!(format!("{}", x), "3.00 + ε1.00");
+/// let adapter = x.display_ascii();
+/// assert_eq!(format!("{}", &adapter), "3.00 + 1.00*eps");
+/// assert_eq!(DualNumber::new(1, 2).display_with("h").to_string(), "1 + 2*h");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DualDisplay<'a, T: 'a> {
+    x: &'a DualNumber<T>,
+    symbol: &'a str,
+}
+
+impl<T> DualNumber<T> {
+    /// Returns an adapter that displays the dual number in ASCII, as `real + dual*eps`.
+    #[inline]
+    pub fn display_ascii<'a>(&'a self) -> DualDisplay<'a, T> {
+        self.display_with("eps")
+    }
+
+    /// Returns an adapter that displays the dual number as `real + dual*symbol`.
+    #[inline]
+    pub fn display_with<'a>(&'a self, symbol: &'a str) -> DualDisplay<'a, T> {
+        DualDisplay { x: self, symbol }
+    }
 }
 
-impl<T: Zero> From<T> for DualNumber<T> {
-    fn from(real: T) -> DualNumber<T> {
-        DualNumber::from_real(real)
+impl<'a, T: Display> Display for DualDisplay<'a, T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let precision = f.precision().unwrap_or(2);
+
+        write!(f, "{:.p$} + {:.p$}*{}", self.x.0, self.x.1, self.symbol, p = precision)
     }
 }
 
-impl<T: Copy> DualNumber<T> {
-    /// Returns the real part
-    #[inline(always)]
-    pub fn real(&self) -> T { self.0 }
+/// Scientific notation for both parts with `{:e}`, or with `{:#e}`, a choice per part like Python's `repr`.
+///
+/// In the alternate form, a part is printed in fixed notation if `1e-4 ≤ |x| < 1e16`, or if it is zero,
+/// infinite or NaN, and in scientific notation otherwise. Without a precision, each part is printed with
+/// the shortest representation that round trips, as with `{}` and `{:e}` on `T`.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let x = DualNumber::new(6.02e23f64, 1.6e-19);
+/// assert_eq!(format!("{:e}", x), "6.02e23 + ε1.6e-19");
+/// assert_eq!(format!("{:#e}", x), "6.02e23 + ε1.6e-19");
+///
+/// let y = DualNumber::new(1234.5f64, 1e-20);
+/// assert_eq!(format!("{:e}", y), "1.2345e3 + ε1e-20");
+/// assert_eq!(format!("{:#e}", y), "1234.5 + ε1e-20");
+/// assert_eq!(format!("{:#.3e}", y), "1234.500 + ε1.000e-20");
+///
+/// // Thresholds
+/// assert_eq!(format!("{:#e}", DualNumber::new(1e16f64, 9.9e15)), "1e16 + ε9900000000000000");
+/// assert_eq!(format!("{:#e}", DualNumber::new(1e-4f64, -9.9e-5)), "0.0001 + ε-9.9e-5");
+/// assert_eq!(format!("{:#e}", DualNumber::new(0.0f64, f64::INFINITY)), "0 + εinf");
+/// ```
+impl<T: Float + Display + LowerExp> LowerExp for DualNumber<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let (low, high) = (T::from(1e-4).unwrap(), T::from(1e16).unwrap());
+        let fixed = |x: T| f.alternate() && (x.is_zero() || !x.is_finite() || (x.abs() >= low && x.abs() < high));
 
-    /// Returns the dual part
-    #[inline(always)]
-    pub fn dual(&self) -> T { self.1 }
+        let (real, dual) = (fixed(self.0), fixed(self.1));
+
+        write_part(f, self.0, real)?;
+        write!(f, " + \u{03B5}")?;
+        write_part(f, self.1, dual)
+    }
 }
 
-impl<T: Float> DualNumber<T> {
-    /// Returns the conjugate of the dual number.
-    pub fn conjugate(self) -> Self {
-        DualNumber(self.real(), self.dual().neg())
+/// Writes `x` in fixed or scientific notation, with the precision of the formatter if any.
+fn write_part<T: Display + LowerExp>(f: &mut Formatter, x: T, fixed: bool) -> FmtResult {
+    match (fixed, f.precision()) {
+        (true, Some(p)) => write!(f, "{:.*}", p, x),
+        (true, None) => write!(f, "{}", x),
+        (false, Some(p)) => write!(f, "{:.*e}", p, x),
+        (false, None) => write!(f, "{:e}", x),
     }
 }
 
-impl<T: Display> Display for DualNumber<T> {
+/// Compact display of a slice of dual numbers, such as a gradient vector
+///
+/// Each element is printed as `real+εdual` with the formatter's precision (two decimals by default).
+///
+/// ```rust
+/// # use dual_num::{DualNumber, DualSlice};
+/// let v = [DualNumber::new(1.0, 0.5), DualNumber::new(2.0, -1.0), DualNumber::new(3.25, 0.0)];
+///
+/// assert_eq!(format!("{}", DualSlice(&v)), "[1.00+ε0.50, 2.00+ε-1.00, 3.25+ε0.00]");
+/// assert_eq!(format!("{:.1}", DualSlice(&v)), "[1.0+ε0.5, 2.0+ε-1.0, 3.2+ε0.0]");
+/// assert_eq!(format!("{}", DualSlice::<f64>(&[])), "[]");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DualSlice<'a, T: 'a>(pub &'a [DualNumber<T>]);
+
+impl<'a, T: Display> Display for DualSlice<'a, T> {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         let precision = f.precision().unwrap_or(2);
 
-        write!(f, "{:.p$} + \u{03B5}{:.p$}", self.0, self.1, p = precision)
+        f.write_str("[")?;
+
+        for (i, x) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{:.p$}+\u{03B5}{:.p$}", x.0, x.1, p = precision)?;
+        }
+
+        f.write_str("]")
     }
 }
 
@@ -224,96 +2024,193 @@ impl_primitive_cast!(
     to_f64,     from_f64    - f64
 );
 
-impl<T: Num + Copy> Add<T> for DualNumber<T> {
+/// Casts the real part with `as` semantics, dropping the dual part as `ToPrimitive` does.
+///
+/// ```rust
+/// # extern crate num_traits;
+/// # extern crate dual_num;
+/// # use dual_num::DualNumberF64;
+/// use num_traits::AsPrimitive;
+///
+/// # fn main() {
+/// let x = DualNumberF64::new(-2.75, 3.0);
+///
+/// let y: f32 = x.as_();
+/// let z: i32 = x.as_();
+///
+/// assert_eq!(y, -2.75f32);
+/// assert_eq!(z, -2);
+/// # }
+/// ```
+impl<T, U> AsPrimitive<U> for DualNumber<T> where T: AsPrimitive<U>, U: 'static + Copy {
+    #[inline]
+    fn as_(self) -> U {
+        self.0.as_()
+    }
+}
+
+impl<T: Num + Clone> Add<T> for DualNumber<T> {
     type Output = DualNumber<T>;
 
     #[inline]
     fn add(self, rhs: T) -> DualNumber<T> {
-        DualNumber::new(self.real() + rhs,
-                        self.dual())
+        DualNumber::new(self.0 + rhs,
+                        self.1)
     }
 }
 
-impl<T: Num + Copy> Sub<T> for DualNumber<T> {
+impl<T: Num + Clone> Sub<T> for DualNumber<T> {
     type Output = DualNumber<T>;
 
     #[inline]
     fn sub(self, rhs: T) -> DualNumber<T> {
-        DualNumber::new(self.real() - rhs,
-                        self.dual())
+        DualNumber::new(self.0 - rhs,
+                        self.1)
     }
 }
 
-impl<T: Num + Copy> Mul<T> for DualNumber<T> {
+/// Scales both parts by a constant, `(x c, x' c)`.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let x = DualNumber::new(1.7f64, -0.3);
+///
+/// // Bit for bit the same as multiplying by a constant dual number
+/// for &c in &[3.0f64, -0.1, 1e-10, 7e15] {
+///     assert_eq!((x * c).into_tuple(), (x * DualNumber::from_real(c)).into_tuple());
+///     assert_eq!((x * c).into_tuple(), (1.7 * c, -0.3 * c));
+/// }
+///
+/// // The dual part does not pick up `∞ · 0` from the real part, and keeps the sign of underflows
+/// assert_eq!((DualNumber::new(f64::INFINITY, 1.0) * 2.0).into_tuple(), (f64::INFINITY, 2.0));
+/// let tiny = DualNumber::new(1.0f64, -1e-300) * 1e-300;
+/// assert!(tiny.dual() == 0.0 && tiny.dual().is_sign_negative());
+/// ```
+impl<T: Num + Clone> Mul<T> for DualNumber<T> {
     type Output = DualNumber<T>;
 
+    #[inline]
     fn mul(self, rhs: T) -> DualNumber<T> {
-        self * DualNumber::from_real(rhs)
+        DualNumber::new(self.0 * rhs.clone(), self.1 * rhs)
     }
 }
 
-impl<T: Num + Copy> Div<T> for DualNumber<T> {
+/// Divides both parts by a constant, `(x / c, x' / c)`.
+///
+/// This does not go through the quotient rule of `x / DualNumber::from_real(c)`, whose `c'` is zero
+/// anyway, so it saves the multiplications and the `c²` that can overflow or underflow. The real
+/// parts are the same either way, and the dual parts agree to within a couple of ulps where `c²`
+/// stays in range. In tight loops the two divisions dominate either way, so the saved multiplications
+/// mostly matter on targets where they are not free (compare `div scalar` and `div from_real` in the
+/// `scalar_ops` bench).
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let x = DualNumber::new(1.7f64, -0.3);
+///
+/// // The same results as the full quotient rule with a constant divisor
+/// for &c in &[3.0f64, -0.1, 1e-10, 7e15, 0.3, 1.0, -2.5e-3] {
+///     let (direct, quotient) = (x / c, x / DualNumber::from_real(c));
+///     assert_eq!(direct.real(), quotient.real());
+///     assert!((direct.dual() - quotient.dual()).abs() <= 2.0 * f64::EPSILON * direct.dual().abs());
+/// }
+///
+/// // Exactly the same as dividing the parts
+/// for &c in &[3.0f64, -0.1, 1e-10, 7e15, 0.3] {
+///     assert_eq!((x / c).into_tuple(), (1.7 / c, -0.3 / c));
+/// }
+///
+/// // Tiny, huge and subnormal constants, whose square underflows or overflows
+/// for &(re, du, c) in &[(1.7f64, -0.3f64, 1e-200f64), (1.7, -0.3, -1e200), (1e-300, -3e-300, 1e-310)] {
+///     let q = DualNumber::new(re, du) / c;
+///     assert!(q.real().is_finite() && q.dual().is_finite() && q.dual() != 0.0);
+///     assert_eq!(q.into_tuple(), (re / c, du / c));
+/// }
+/// ```
+impl<T: Num + Clone> Div<T> for DualNumber<T> {
     type Output = DualNumber<T>;
 
     #[inline]
     fn div(self, rhs: T) -> DualNumber<T> {
-        self / DualNumber::from_real(rhs)
+        let (a, da) = self.into_tuple();
+
+        DualNumber::new(a / rhs.clone(), da / rhs)
     }
 }
 
-impl<T: Signed + Copy> Neg for DualNumber<T> {
+impl<T: Neg<Output = T>> Neg for DualNumber<T> {
     type Output = Self;
 
     #[inline]
     fn neg(self) -> Self {
-        DualNumber::new(self.real().neg(),
-                        self.dual().neg())
+        DualNumber::new(self.0.neg(),
+                        self.1.neg())
     }
 }
 
-impl<T: Num + Copy> Add<Self> for DualNumber<T> {
+impl<T: Num + Clone> Add<Self> for DualNumber<T> {
     type Output = Self;
 
     #[inline]
     fn add(self, rhs: Self) -> Self {
-        DualNumber::new(self.real() + rhs.real(),
-                        self.dual() + rhs.dual())
+        DualNumber::new(self.0 + rhs.0,
+                        self.1 + rhs.1)
     }
 }
 
-impl<T: Num + Copy> Sub<Self> for DualNumber<T> {
+impl<T: Num + Clone> Sub<Self> for DualNumber<T> {
     type Output = Self;
 
     #[inline]
     fn sub(self, rhs: Self) -> Self {
-        DualNumber::new(self.real() - rhs.real(),
-                        self.dual() - rhs.dual())
+        DualNumber::new(self.0 - rhs.0,
+                        self.1 - rhs.1)
     }
 }
 
-impl<T: Num + Copy> Mul<Self> for DualNumber<T> {
+impl<T: Num + Clone> Mul<Self> for DualNumber<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
+        let (a, da) = self.into_tuple();
+        let (b, db) = rhs.into_tuple();
+
         DualNumber::new(
-            self.real() * rhs.real(),
-            self.real() * rhs.dual() + self.dual() * rhs.real()
+            a.clone() * b.clone(),
+            a * db + da * b
         )
     }
 }
 
-impl<T: Num + Copy> Div<Self> for DualNumber<T> {
+/// Quotient rule division, `(a/b, (a'b - ab')/b²)`. See `div_newton` with the `newton-div` feature
+/// for a version without a hardware divide.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let (a, b) = (DualNumber::new(3.0f64, 1.0), DualNumber::new(7.0f64, -2.0));
+///
+/// assert_eq!((a / b).into_tuple(), (3.0 / 7.0, (1.0 * 7.0 + 3.0 * 2.0) / 49.0));
+///
+/// assert_eq!((DualNumber::new(1.0f64, 0.0) / DualNumber::new(-0.0f64, 0.0)).real(), f64::NEG_INFINITY);
+/// assert_eq!((DualNumber::new(1.0f64, 0.0) / DualNumber::new(f64::INFINITY, 0.0f64)).real(), 0.0);
+///
+/// // Integer parts divide as well
+/// assert_eq!((DualNumber::new(6i32, 1) / DualNumber::new(2i32, 0)).into_tuple(), (3, 0));
+/// assert_eq!((DualNumber::new(6i32, 4) / 2).into_tuple(), (3, 2));
+/// ```
+impl<T: Num + Clone> Div<Self> for DualNumber<T> {
     type Output = Self;
 
+    #[inline]
     fn div(self, rhs: Self) -> Self {
-        DualNumber::new(
-            self.real() / rhs.real(),
-            (self.dual() * rhs.real() - self.real() * rhs.dual()) / (rhs.real() * rhs.real())
-        )
+        let (a, da) = self.into_tuple();
+        let (b, db) = rhs.into_tuple();
+
+        DualNumber::new(a.clone() / b.clone(), quotient_dual(a, da, b, db))
     }
 }
 
-impl<T: Num + Copy> Rem<Self> for DualNumber<T> {
+impl<T: Num + Clone> Rem<Self> for DualNumber<T> {
     type Output = Self;
 
     /// **UNIMPLEMENTED!!!**
@@ -325,15 +2222,176 @@ impl<T: Num + Copy> Rem<Self> for DualNumber<T> {
     }
 }
 
+/// Widens both parts of an `f32` dual number to `f64`, which is exact.
+#[inline]
+fn promote(x: DualNumber<f32>) -> DualNumber<f64> {
+    DualNumber::new(x.real().into(), x.dual().into())
+}
+
+/// Widens both parts to `f64`, which is exact.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// use std::convert::TryFrom;
+///
+/// let x = DualNumber::new(0.1f32, -3e38);
+/// let wide = DualNumber::<f64>::from(x);
+/// assert_eq!(wide.into_tuple(), (0.1f32 as f64, -3e38f32 as f64));
+///
+/// // Narrowing back gives the same parts
+/// assert_eq!(DualNumber::<f32>::try_from(wide).unwrap().into_tuple(), x.into_tuple());
+/// ```
+impl From<DualNumber<f32>> for DualNumber<f64> {
+    #[inline]
+    fn from(x: DualNumber<f32>) -> DualNumber<f64> {
+        promote(x)
+    }
+}
+
+/// Narrows both parts to `f32`, rounding to the nearest value, or fails with `DualError::Overflow`
+/// if a finite part is too large for an `f32`.
+///
+/// Parts that are already infinite or NaN are converted as they are, and tiny parts may round to
+/// subnormals or zero.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber};
+/// use std::convert::TryFrom;
+///
+/// let narrow = DualNumber::<f32>::try_from(DualNumber::new(0.1f64, 1e-50)).unwrap();
+/// assert_eq!(narrow.into_tuple(), (0.1, 0.0));
+///
+/// assert_eq!(DualNumber::<f32>::try_from(DualNumber::new(1.0f64, 1e39)).unwrap_err(), DualError::Overflow);
+/// assert_eq!(DualNumber::<f32>::try_from(DualNumber::new(-1e300f64, 0.0)).unwrap_err(), DualError::Overflow);
+///
+/// let special = DualNumber::<f32>::try_from(DualNumber::new(f64::NEG_INFINITY, f64::NAN)).unwrap();
+/// assert!(special.real() == f32::NEG_INFINITY && special.dual().is_nan());
+///
+/// // Just above f32::MAX still rounds down to it
+/// let max = DualNumber::<f32>::try_from(DualNumber::new(f32::MAX as f64 * (1.0 + 1e-9), 0.0)).unwrap();
+/// assert_eq!(max.real(), f32::MAX);
+/// ```
+impl TryFrom<DualNumber<f64>> for DualNumber<f32> {
+    type Error = DualError;
+
+    fn try_from(x: DualNumber<f64>) -> Result<DualNumber<f32>, DualError> {
+        let narrow = |v: f64| {
+            let n = v as f32;
+
+            if n.is_infinite() && v.is_finite() { Err(DualError::Overflow) } else { Ok(n) }
+        };
+
+        Ok(DualNumber::new(narrow(x.real())?, narrow(x.dual())?))
+    }
+}
+
+/// Mixed precision product, which promotes the `f32` operand to `f64`.
+///
+/// Multiplying and dividing an `f64` dual number by an `f32` one, on either side, always gives an
+/// `f64` result, computed entirely in `f64` after widening the `f32` parts exactly. There is no
+/// implicit narrowing, so getting an `f32` result takes an explicit conversion of the `f64` operand
+/// (or of the result, with `TryFrom` or `cast`), and sums and differences of mixed precisions are
+/// not implemented.
+///
+/// Since the other operand of a product or quotient may be of either precision, generic constants such
+/// as `DualNumber::PI()` and dual numbers built from untyped float literals need their type spelled out,
+/// as in `x * DualNumber::<f32>::PI()` or `DualNumber::new(2.0f64, 0.0)`.
+///
+/// ```rust
+/// # use dual_num::DualNumber;
+/// let x = DualNumber::new(1.5f64, 2.0);
+/// let y = DualNumber::new(0.1f32, 1.0);
+///
+/// let wide = DualNumber::new(0.1f32 as f64, 1.0);
+/// assert_eq!((x * y).into_tuple(), (x * wide).into_tuple());
+/// assert_eq!((y * x).into_tuple(), (wide * x).into_tuple());
+/// assert_eq!((x / y).into_tuple(), (x / wide).into_tuple());
+/// assert_eq!((y / x).into_tuple(), (wide / x).into_tuple());
+///
+/// // The f32 value 0.1 is not the f64 value 0.1
+/// assert!((x * y).real() != 1.5 * 0.1);
+///
+/// let product: DualNumber<f64> = DualNumber::new(2.0f32, 1.0) * DualNumber::new(3.0f64, 0.5) * y;
+/// assert_eq!(product.into_tuple(), (6.0 * 0.1f32 as f64, 4.0 * 0.1f32 as f64 + 6.0));
+/// ```
+impl Mul<DualNumber<f32>> for DualNumber<f64> {
+    type Output = DualNumber<f64>;
+
+    #[inline]
+    fn mul(self, rhs: DualNumber<f32>) -> DualNumber<f64> {
+        self * promote(rhs)
+    }
+}
+
+impl Mul<DualNumber<f64>> for DualNumber<f32> {
+    type Output = DualNumber<f64>;
+
+    #[inline]
+    fn mul(self, rhs: DualNumber<f64>) -> DualNumber<f64> {
+        promote(self) * rhs
+    }
+}
+
+impl Div<DualNumber<f32>> for DualNumber<f64> {
+    type Output = DualNumber<f64>;
+
+    #[inline]
+    fn div(self, rhs: DualNumber<f32>) -> DualNumber<f64> {
+        self / promote(rhs)
+    }
+}
+
+impl Div<DualNumber<f64>> for DualNumber<f32> {
+    type Output = DualNumber<f64>;
+
+    #[inline]
+    fn div(self, rhs: DualNumber<f64>) -> DualNumber<f64> {
+        promote(self) / rhs
+    }
+}
+
+/// Sign operations, for integer and float parts alike.
+///
+/// `abs` negates both parts when the real part is negative, which for floats means its sign bit is set,
+/// so that it agrees with `Float::abs` on `-0.0` and NaN as well.
+///
+/// ```rust
+/// # extern crate num_traits;
+/// # extern crate dual_num;
+/// # use dual_num::DualNumber;
+/// # use num_traits::Signed;
+/// # fn main() {
+/// let x = DualNumber::new(-3i32, 2);
+///
+/// assert_eq!(x.abs().into_tuple(), (3, -2));
+/// assert_eq!(x.signum().into_tuple(), (-1, 0));
+/// assert_eq!(DualNumber::new(0i32, 2).signum().into_tuple(), (0, 0));
+/// assert!(x.is_negative() && !x.is_positive());
+/// assert!(DualNumber::new(3i32, -2).is_positive());
+///
+/// assert_eq!(DualNumber::new(5i32, 1).abs_sub(&x).into_tuple(), (8, -1));
+/// assert_eq!(x.abs_sub(&DualNumber::new(5, 1)).into_tuple(), (0, 0));
+/// assert_eq!(x.abs_sub(&x).into_tuple(), (0, 0));
+///
+/// // Like `Float::abs`, the sign bit of the real part decides, for zeros and NaN as well
+/// for &v in &[-0.0f64, 0.0, -f64::NAN, f64::NAN, -2.5, f64::NEG_INFINITY] {
+///     let x = DualNumber::new(v, 2.0);
+///     let (a, b) = (Signed::abs(&x), dual_num::Float::abs(x));
+///     assert_eq!((a.real().to_bits(), a.dual()), (b.real().to_bits(), b.dual()));
+/// }
+/// assert_eq!(Signed::abs(&DualNumber::new(-f64::NAN, 2.0)).dual(), -2.0);
+/// # }
+/// ```
 impl<T> Signed for DualNumber<T> where T: Signed + Copy + PartialOrd {
     #[inline]
     fn abs(&self) -> Self {
-        DualNumber::new(self.real().abs(), self.dual() * self.real().signum())
+        select(self.is_negative(), -*self, *self)
     }
 
+    /// The positive difference of two numbers, zero (with a zero dual part) if `self <= rhs`.
     fn abs_sub(&self, rhs: &Self) -> Self {
         if self.real() > rhs.real() {
-            DualNumber::new(self.real() - rhs.real(), self.sub(*rhs).dual())
+            *self - *rhs
         } else {
             Self::zero()
         }
@@ -357,26 +2415,29 @@ impl<T> Signed for DualNumber<T> where T: Signed + Copy + PartialOrd {
 
 impl<T: Unsigned> Unsigned for DualNumber<T> where Self: Num {}
 
-impl<T: Num + Zero + Copy> Zero for DualNumber<T> {
+impl<T: Num + Clone> Zero for DualNumber<T> {
     #[inline]
     fn zero() -> DualNumber<T> {
         DualNumber::new(T::zero(), T::zero())
     }
 
+    /// Returns true if the real part is zero, regardless of the dual part.
+    ///
+    /// See `is_exactly_zero` to check both parts.
     #[inline]
     fn is_zero(&self) -> bool {
-        self.real().is_zero()
+        self.0.is_zero()
     }
 }
 
-impl<T: Num + One + Copy> One for DualNumber<T> {
+impl<T: Num + Clone> One for DualNumber<T> {
     #[inline]
     fn one() -> DualNumber<T> {
         DualNumber::new(T::one(), T::zero())
     }
 }
 
-impl<T: Num + Copy> Num for DualNumber<T> {
+impl<T: Num + Clone> Num for DualNumber<T> {
     type FromStrRadixErr = <T as Num>::FromStrRadixErr;
 
     fn from_str_radix(str: &str, radix: u32) -> Result<DualNumber<T>, Self::FromStrRadixErr> {
@@ -492,71 +2553,507 @@ impl<T> Float for DualNumber<T> where T: Float + Signed + FloatConst {
 
     #[inline]
     fn signum(self) -> Self {
-        DualNumber::from_real(self.real().signum())
+        Signed::signum(&self)
     }
 
+    /// Absolute value, negating both parts when the sign bit of the real part is set.
+    ///
+    /// Unlike `Signed::abs`, which multiplies the dual part by the `signum` of the real part, this
+    /// only selects between `self` and `-self`, so it has no branch. The real part is exactly the
+    /// primitive `abs`, and a real part of `-0.0` or a NaN with its sign bit set negates the dual part.
+    ///
+    /// ```rust
+    /// # extern crate num_traits;
+    /// # extern crate dual_num;
+    /// # use dual_num::{DualNumber, Float};
+    /// # use num_traits::Signed;
+    /// # fn main() {
+    /// assert_eq!(Float::abs(DualNumber::new(0.0f64, 2.0)).into_tuple(), (0.0, 2.0));
+    /// let negative_zero = Float::abs(DualNumber::new(-0.0f64, 2.0));
+    /// assert!(negative_zero.real().is_sign_positive());
+    /// assert_eq!(negative_zero.into_tuple(), (0.0, -2.0));
+    ///
+    /// let nan = Float::abs(DualNumber::new(-f64::NAN, 2.0));
+    /// assert!(nan.real().is_nan() && nan.real().is_sign_positive());
+    /// assert_eq!(nan.dual(), -2.0);
+    ///
+    /// let subnormal = Float::abs(DualNumber::new(-1e-310f64, 3.0));
+    /// assert_eq!(subnormal.into_tuple(), (1e-310, -3.0));
+    ///
+    /// // The same results as `Signed::abs`
+    /// for &v in &[-1e300f64, -2.5, -1e-310, 1e-310, 0.0, 4.0, f64::INFINITY, f64::NEG_INFINITY] {
+    ///     let x = DualNumber::new(v, -1.25);
+    ///     assert_eq!(Float::abs(x).into_tuple(), Signed::abs(&x).into_tuple());
+    /// }
+    /// # }
+    /// ```
     #[inline]
     fn abs(self) -> Self {
-        DualNumber::new(self.real().abs(), self.dual() * self.real().signum())
+        select(self.is_sign_negative(), -self, self)
     }
 
+    /// Returns the dual number with the larger real part, keeping its dual part.
+    ///
+    /// Like `f64::max`, if one of the real parts is NaN the other operand is returned.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(1.0f64, 2.0);
+    /// let nan = DualNumber::new(f64::NAN, 3.0);
+    ///
+    /// assert_eq!(x.max(DualNumber::new(0.0, 5.0)).into_tuple(), (1.0, 2.0));
+    /// assert_eq!(x.max(nan).into_tuple(), (1.0, 2.0));
+    /// assert_eq!(nan.max(x).into_tuple(), (1.0, 2.0));
+    /// ```
     fn max(self, other: Self) -> Self {
-        if self.real() > other.real() { self } else { other }
+        if self.real().is_nan() {
+            other
+        } else if other.real().is_nan() || self.real() > other.real() {
+            self
+        } else {
+            other
+        }
     }
 
+    /// Returns the dual number with the smaller real part, keeping its dual part.
+    ///
+    /// Like `f64::min`, if one of the real parts is NaN the other operand is returned.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(1.0f64, 2.0);
+    /// let nan = DualNumber::new(f64::NAN, 3.0);
+    ///
+    /// assert_eq!(x.min(DualNumber::new(0.0, 5.0)).into_tuple(), (0.0, 5.0));
+    /// assert_eq!(x.min(nan).into_tuple(), (1.0, 2.0));
+    /// assert_eq!(nan.min(x).into_tuple(), (1.0, 2.0));
+    /// ```
     fn min(self, other: Self) -> Self {
-        if self.real() < other.real() { other } else { self }
+        if self.real().is_nan() {
+            other
+        } else if other.real().is_nan() || self.real() < other.real() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts the real part to `[min, max]`, computed as `self.max(min).min(max)`.
+    ///
+    /// The result keeps the dual part of whichever operand is selected, so a clamped value takes on
+    /// the derivative of the bound. On a boundary the bound is selected, and a NaN real part yields `min`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let lo = DualNumber::new(-1.0f64, 10.0);
+    /// let hi = DualNumber::new(1.0f64, 20.0);
+    /// let x = |v| DualNumber::new(v, 2.0);
+    ///
+    /// assert_eq!(x(-5.0).clamp(lo, hi).into_tuple(), (-1.0, 10.0));
+    /// assert_eq!(x(-1.0).clamp(lo, hi).into_tuple(), (-1.0, 10.0));
+    /// assert_eq!(x(0.5).clamp(lo, hi).into_tuple(), (0.5, 2.0));
+    /// assert_eq!(x(1.0).clamp(lo, hi).into_tuple(), (1.0, 20.0));
+    /// assert_eq!(x(5.0).clamp(lo, hi).into_tuple(), (1.0, 20.0));
+    ///
+    /// // Negative bounds and dual parts keep their signs
+    /// let (lo, hi) = (DualNumber::new(-3.0f64, -1.0), DualNumber::new(-2.0f64, -4.0));
+    /// assert_eq!(x(-10.0).clamp(lo, hi).into_tuple(), (-3.0, -1.0));
+    /// assert_eq!(DualNumber::new(-2.5f64, -7.0).clamp(lo, hi).into_tuple(), (-2.5, -7.0));
+    /// assert_eq!(x(10.0).clamp(lo, hi).into_tuple(), (-2.0, -4.0));
+    ///
+    /// assert_eq!(x(f64::NAN).clamp(lo, hi).into_tuple(), (-3.0, -1.0));
+    /// ```
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
     }
 
+    #[inline]
     fn abs_sub(self, rhs: Self) -> Self {
-        if self.real() > rhs.real() {
-            DualNumber::new(self.real() - rhs.real(), (self - rhs).dual())
-        } else {
-            Self::zero()
-        }
+        Signed::abs_sub(&self, &rhs)
+    }
+
+    /// Returns `self` with the sign of the real part of `sign`, flipping the dual part along with it.
+    ///
+    /// See `copysign_scalar` for details.
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        self.copysign_scalar(sign.real())
     }
 
+    /// Fused multiply-add, `(x a + b, x' a + x a' + b')`.
+    ///
+    /// The real part is computed with a single rounding by `T::mul_add`, while the dual part uses
+    /// ordinary multiplications and additions. A NaN real part, from any of the operands or from
+    /// `∞ · 0`, makes the dual part NaN as well, since the derivative of an undefined value is
+    /// undefined. A NaN dual part only affects the dual part of the result.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// // (1 + 2⁻²⁷)(1 - 2⁻²⁷) - 1 = -2⁻⁵⁴ exactly, which the unfused product rounds away
+    /// let e = 2f64.powi(-27);
+    /// let (x, a, b) = (DualNumber::new(1.0 + e, 1.0), DualNumber::new(1.0 - e, 2.0), DualNumber::new(-1.0, 0.5));
+    ///
+    /// let fused = x.mul_add(a, b);
+    /// assert_eq!(fused.real(), -2f64.powi(-54));
+    /// assert_eq!(x.real() * a.real() + b.real(), 0.0);
+    /// assert_eq!((x * a + b).real(), 0.0);
+    ///
+    /// // The dual part is the product rule, the same as the unfused expression
+    /// assert_eq!(fused.dual(), (x * a + b).dual());
+    /// assert_eq!(fused.dual(), 1.0 * (1.0 - e) + (1.0 + e) * 2.0 + 0.5);
+    ///
+    /// // A NaN real part in any operand spreads to both parts
+    /// let nan = DualNumber::new(f64::NAN, 1.0);
+    /// for y in &[nan.mul_add(a, b), x.mul_add(nan, b), x.mul_add(a, nan)] {
+    ///     assert!(y.real().is_nan() && y.dual().is_nan());
+    /// }
+    /// let inf_times_zero = DualNumber::new(f64::INFINITY, 0.0).mul_add(DualNumber::new(0.0, 0.0), b);
+    /// assert!(inf_times_zero.real().is_nan() && inf_times_zero.dual().is_nan());
+    ///
+    /// // A NaN dual part leaves the real part alone
+    /// let y = DualNumber::new(2.0f64, f64::NAN).mul_add(a, b);
+    /// assert_eq!(y.real(), 2.0f64.mul_add(1.0 - e, -1.0));
+    /// assert!(y.dual().is_nan());
+    /// ```
     fn mul_add(self, a: Self, b: Self) -> Self {
-        DualNumber::new(self.real().mul_add(a.real(), b.real()),
-                        self.dual() * a.real() + self.real() * a.dual() + b.dual())
+        let real = self.real().mul_add(a.real(), b.real());
+
+        if real.is_nan() {
+            return DualNumber::new(real, real);
+        }
+
+        DualNumber::new(real, self.dual() * a.real() + self.real() * a.dual() + b.dual())
     }
 
+    /// Reciprocal, `(1/x, -x'/x²)`, computed with a single division as `(r, -x' r r)` for `r = 1/x`.
+    ///
+    /// The real part is the same as `one() / x`, and the dual part agrees with the quotient rule to within a
+    /// few ulps. Zero and tiny real parts, where `r²` overflows, give an infinite dual part, and an infinite
+    /// `x` gives a zero dual part with the opposite sign of `x'` rather than NaN.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, One};
+    /// let one = DualNumber::<f64>::one();
+    ///
+    /// for i in -300..300 {
+    ///     let x = DualNumber::new(1.37f64.powi(i) * if i % 3 == 0 { -1.0 } else { 1.0 }, 0.3 * i as f64);
+    ///     let (r, q) = (x.recip(), one / x);
+    ///
+    ///     assert_eq!(r.real(), q.real());
+    ///     assert!((r.dual() - q.dual()).abs() <= 4.0 * f64::EPSILON * q.dual().abs());
+    /// }
+    ///
+    /// assert_eq!(DualNumber::new(4.0f64, 2.0).recip().into_tuple(), (0.25, -0.125));
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).recip().into_tuple(), (f64::INFINITY, f64::NEG_INFINITY));
+    /// assert_eq!(DualNumber::new(1e-308f64, 1.0).recip().dual(), f64::NEG_INFINITY);
+    /// assert!(DualNumber::new(0.0f64, 0.0).recip().dual().is_nan());
+    /// assert_eq!(DualNumber::new(f64::INFINITY, 1.0).recip().into_tuple(), (0.0, -0.0));
+    /// ```
     #[inline]
     fn recip(self) -> Self {
-        Self::one() / self
+        let inv = self.real().recip();
+
+        DualNumber::new(inv, -self.dual() * inv * inv)
     }
 
+    /// Raises `self` to an integer power, with derivative `n x^(n-1) x'`.
+    ///
+    /// For negative `n` the value is the reciprocal `p = 1/x^|n|`, and the dual part is `n x' p / x`,
+    /// so that `x^(n-1)` is never formed on its own. It can overflow for small `x` even when the
+    /// derivative itself is representable.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(1e-61f64, 1e-70);
+    /// let y = x.powi(-5);
+    ///
+    /// // The naive n x^(n-1) x' overflows in x^-6
+    /// assert!((-5.0 * x.real().powi(-6) * x.dual()).is_infinite());
+    /// assert!((y.real() - 1e305).abs() < 1e291);
+    /// assert!((y.dual() + 5e296).abs() < 1e283);
+    ///
+    /// for &v in &[0.3f64, -1.7, 2.5, 10.0] {
+    ///     let x = DualNumber::new(v, 1.0);
+    ///     for n in -8..8 {
+    ///         let expected = n as f64 * v.powi(n - 1);
+    ///         assert_eq!(x.powi(n).real(), v.powi(n));
+    ///         assert!((x.powi(n).dual() - expected).abs() <= 1e-15 * expected.abs());
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(DualNumber::new(2.0f64, 1.0).powi(i32::MIN).into_tuple(), (0.0, -0.0));
+    /// ```
+    ///
+    /// Exponents with `|n| ≤ 4` are unrolled into dual multiplications, `x x`, `x x x` and `(x x)(x x)`,
+    /// which share the intermediate powers between both parts instead of calling the float `powi`
+    /// twice. Negative small exponents take the reciprocal of the unrolled power.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for &(v, d) in &[(1.1f64, 0.7f64), (-2.3, 1.0), (1e-5, -3.0), (0.0, 1.0), (7.25, 1e10)] {
+    ///     let x = DualNumber::new(v, d);
+    ///
+    ///     assert_eq!(x.powi(0).into_tuple(), (1.0, 0.0));
+    ///     assert_eq!(x.powi(1).into_tuple(), x.into_tuple());
+    ///     assert_eq!(x.powi(2).into_tuple(), (x * x).into_tuple());
+    ///     assert_eq!(x.powi(3).into_tuple(), (x * x * x).into_tuple());
+    ///     assert_eq!(x.powi(4).into_tuple(), ((x * x) * (x * x)).into_tuple());
+    /// }
+    ///
+    /// // Within an ulp of the general formula at the crossover
+    /// let ulp = |a: f64, b: f64| (a.to_bits() as i64 - b.to_bits() as i64).abs();
+    /// for &v in &[1.1f64, -2.3, 0.37, 123.0] {
+    ///     let x = DualNumber::new(v, 0.7);
+    ///     assert!(ulp(x.powi(4).real(), v.powi(4)) <= 1);
+    ///     assert!(ulp(x.powi(4).dual(), 4.0 * v.powi(3) * 0.7) <= 1);
+    ///     assert!(ulp(x.powi(-4).real(), 1.0 / v.powi(4)) <= 1);
+    ///     assert!(ulp(x.powi(-4).dual(), -4.0 * 0.7 / v.powi(5)) <= 1);
+    /// }
+    ///
+    /// let x = DualNumber::new(2.0f64, 1.0);
+    /// assert_eq!(x.powi(-2).into_tuple(), (0.25, -0.25));
+    /// assert_eq!(x.powi(-3).into_tuple(), (0.125, -0.1875));
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).powi(-2).into_tuple(), (f64::INFINITY, f64::NEG_INFINITY));
+    /// ```
     fn powi(self, n: i32) -> Self {
         let nf = <T as NumCast>::from(n).expect("Invalid value");
 
-        DualNumber::new(self.real().powi(n),
-                        nf * self.real().powi(n - 1) * self.dual())
+        if (-4..=4).contains(&n) {
+            let power = match n.abs() {
+                0 => return DualNumber::new(T::one(), T::zero()),
+                1 => self,
+                2 => self * self,
+                3 => self * self * self,
+                _ => {
+                    let square = self * self;
+
+                    square * square
+                }
+            };
+
+            if n > 0 {
+                return power;
+            }
+
+            let p = power.real().recip();
+
+            return DualNumber::new(p, nf * self.dual() * p / self.real());
+        }
+
+        if n > 0 {
+            return DualNumber::new(self.real().powi(n),
+                                   nf * self.real().powi(n - 1) * self.dual());
+        }
+
+        // -(n + 1) rather than -n, which overflows for i32::MIN
+        let p = (self.real().powi(-(n + 1)) * self.real()).recip();
+
+        DualNumber::new(p, nf * self.dual() * p / self.real())
     }
 
+    /// Raises `self` to a dual power.
+    ///
+    /// By convention, `0^0 = 1` with a zero derivative, rather than the NaN produced by
+    /// the `ln(0)` term of the general formula.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, differentiate};
+    /// let zero = DualNumber::from_real(0.0f64);
+    ///
+    /// assert_eq!(DualNumber::new(0.0, 1.0).powf(zero).into_tuple(), (1.0, 0.0));
+    /// assert_eq!(differentiate(0.0f64, |x| x.powf(zero)), 0.0);
+    ///
+    /// // d/dx x^x = x^x (ln(x) + 1)
+    /// let d = differentiate(1.5f64, |x| x.powf(x));
+    /// assert!((d - 1.5f64.powf(1.5) * (1.5f64.ln() + 1.0)).abs() < 1e-15);
+    /// ```
+    ///
+    /// Constant exponents of one and zero are exact identities, `x^1 = (x, x')` and `x^0 = (1, 0)`,
+    /// with no rounding from the `ln` term. With a seeded exponent of one, the dual part is `x' + x ln(x)`:
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, One, Zero};
+    /// let (one, zero) = (DualNumber::<f64>::one(), DualNumber::<f64>::zero());
+    ///
+    /// for &v in &[1e-300f64, 0.3, 1.0, 2.5, 1e300] {
+    ///     for &d in &[1.0f64, -0.7, 1e10, 0.0] {
+    ///         let x = DualNumber::new(v, d);
+    ///
+    ///         assert_eq!(x.powf(one).into_tuple(), (v, d));
+    ///         assert_eq!(x.powf(zero).into_tuple(), (1.0, 0.0));
+    ///     }
+    /// }
+    ///
+    /// let x = DualNumber::new(2.5f64, 1.0);
+    /// assert_eq!(x.powf(DualNumber::new(1.0, 1.0)).into_tuple(), (2.5, 1.0 + 2.5 * 2.5f64.ln()));
+    /// assert_eq!(x.powf(DualNumber::new(0.0, 1.0)).into_tuple(), (1.0, 2.5f64.ln()));
+    /// ```
+    ///
+    /// When the dual part of the exponent is zero, the `ln(x) n'` term vanishes, so it is skipped and
+    /// only the power rule `n x^(n-1) x'` is evaluated. This saves the `ln` call at the cost of one
+    /// comparison, and gives the derivative for negative and zero bases, where `ln(x) · 0` is NaN:
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let three = DualNumber::from_real(3.0f64);
+    ///
+    /// assert_eq!(DualNumber::new(-2.0f64, 1.0).powf(three).into_tuple(), (-8.0, 12.0));
+    /// assert_eq!(DualNumber::new(-0.5f64, 2.0).powf(DualNumber::from_real(-2.0)).into_tuple(), (4.0, 32.0));
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).powf(three).into_tuple(), (0.0, 0.0));
+    /// assert!(DualNumber::new(-2.0f64, 1.0).powf(DualNumber::from_real(0.5)).real().is_nan());
+    ///
+    /// // The same results as the general formula wherever it is defined
+    /// for &(x, n) in &[(1.5f64, 2.5f64), (0.3, -1.25), (7.0, 0.0), (2.0, 10.0)] {
+    ///     let general = (n * x.powf(n - 1.0)).mul_add(0.75, x.powf(n) * x.ln() * 0.0);
+    ///     let y = DualNumber::new(x, 0.75).powf(DualNumber::from_real(n));
+    ///     assert_eq!(y.into_tuple(), (x.powf(n), general));
+    /// }
+    /// ```
+    ///
+    /// Each term of the derivative is checked on its own for positive bases, so that a regression
+    /// points at the term at fault. With a constant base only `a^b ln(a) b'` remains, and with a
+    /// constant exponent only `b a^(b-1) a'`:
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for &a in &[0.25f64, 1.0, 2.0, 7.5, 1e10] {
+    ///     for &(b, d) in &[(3.0f64, 1.0f64), (-0.5, 2.0), (0.1, -0.3), (12.0, 1e-3)] {
+    ///         // Only the exponent varies
+    ///         let y = DualNumber::from_real(a).powf(DualNumber::new(b, d));
+    ///         assert_eq!(y.into_tuple(), (a.powf(b), a.powf(b) * a.ln() * d));
+    ///
+    ///         // Only the base varies
+    ///         let y = DualNumber::new(a, d).powf(DualNumber::from_real(b));
+    ///         assert_eq!(y.into_tuple(), (a.powf(b), b * a.powf(b - 1.0) * d));
+    ///     }
+    /// }
+    ///
+    /// // d/dt 2^t = 8 ln(2) at t = 3, and the ln term vanishes for a base of one
+    /// assert_eq!(DualNumber::from_real(2.0f64).powf(DualNumber::new(3.0, 1.0)).dual(), 8.0 * 2f64.ln());
+    /// assert_eq!(DualNumber::from_real(1.0f64).powf(DualNumber::new(3.0, 1.0)).dual(), 0.0);
+    /// ```
     fn powf(self, n: Self) -> Self {
+        if self.real().is_zero() && n.real().is_zero() {
+            return Self::one();
+        }
+
         let real = self.real().powf(n.real());
+        let base_term = n.real() * self.real().powf(n.real() - T::one());
 
-        DualNumber::new(real,
-                        n.real() * self.real().powf(n.real() - T::one()) * self.dual() +
-                            real * self.real().ln() * n.dual())
+        if n.dual().is_zero() {
+            return DualNumber::new(real, base_term * self.dual());
+        }
+
+        // The two terms are fused with `mul_add`, which rounds once and is a single
+        // instruction on targets with hardware FMA (falling back to software elsewhere)
+        let exp_term = real * self.real().ln() * n.dual();
+
+        DualNumber::new(real, base_term.mul_add(self.dual(), exp_term))
     }
 
+    /// Exponential, with derivative `dual * exp(real)`.
+    ///
+    /// `exp` and `ln` are inverses of each other in both parts:
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for &v in &[0.1f64, 1.0, 2.5, 40.0] {
+    ///     let x = DualNumber::new(v, 1.5);
+    ///
+    ///     let y = x.ln().exp();
+    ///     assert!((y.real() - v).abs() < 1e-14 * v);
+    ///     assert!((y.dual() - 1.5).abs() < 1e-14);
+    ///
+    ///     let y = x.exp().ln();
+    ///     assert!((y.real() - v).abs() < 1e-15 * v.max(1.0));
+    ///     assert!((y.dual() - 1.5).abs() < 1e-15);
+    /// }
+    /// ```
+    ///
+    /// At the limits, `exp(-inf)` is zero with a zero derivative, even for an infinite dual part where
+    /// `dual · 0` would be NaN, and `exp(inf)` is infinite with a dual part of `inf · dual`. The same
+    /// holds for `exp2` and `exp10`:
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let inf = f64::INFINITY;
+    /// let exps: [fn(DualNumber<f64>) -> DualNumber<f64>; 3] = [Float::exp, Float::exp2, DualNumber::exp10];
+    ///
+    /// for exp in &exps {
+    ///     assert_eq!(exp(DualNumber::new(-inf, 2.0)).into_tuple(), (0.0, 0.0));
+    ///     assert_eq!(exp(DualNumber::new(-inf, inf)).into_tuple(), (0.0, 0.0));
+    ///     assert_eq!(exp(DualNumber::new(-inf, -inf)).into_tuple(), (0.0, -0.0));
+    ///     assert!(exp(DualNumber::new(-inf, f64::NAN)).dual().is_nan());
+    ///
+    ///     assert_eq!(exp(DualNumber::new(inf, 2.0)).into_tuple(), (inf, inf));
+    ///     assert_eq!(exp(DualNumber::new(inf, -1.0)).into_tuple(), (inf, -inf));
+    ///     assert!(exp(DualNumber::new(inf, 0.0)).dual().is_nan());
+    ///
+    ///     // Underflow and overflow of finite arguments
+    ///     assert_eq!(exp(DualNumber::new(-1e4, 2.0)).into_tuple(), (0.0, 0.0));
+    ///     assert_eq!(exp(DualNumber::new(1e4, 2.0)).into_tuple(), (inf, inf));
+    /// }
+    /// ```
     fn exp(self) -> Self {
         let real = self.real().exp();
 
-        DualNumber::new(real, self.dual() * real)
+        self.exp_parts(real, self.dual())
     }
 
     fn exp2(self) -> Self {
         let real = self.real().exp2();
 
-        DualNumber::new(real, self.dual() * T::LN_2() * real)
+        self.exp_parts(real, self.dual() * T::LN_2())
     }
 
+    /// Natural logarithm, with derivative `dual / real`.
+    ///
+    /// Only the first-order term of the expansion `ln(a + bε) = ln(a) + (b/a)ε - (b²/2a²)ε² + ...`
+    /// is kept, since `ε² = 0`.
+    ///
+    /// For a pure dual input (`real == 0`) the value is `-inf` and the derivative is `±inf`,
+    /// matching the pole of `1/x` at zero.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.0f64, 1.0).ln();
+    ///
+    /// assert_eq!(x.real(), f64::NEG_INFINITY);
+    /// assert_eq!(x.dual(), f64::INFINITY);
+    ///
+    /// for &p in &[0.25f64, 1.0, 2.0, 100.0] {
+    ///     assert!((DualNumber::new(p, 1.0).ln().dual() - 1.0 / p).abs() < 1e-15);
+    /// }
+    /// ```
     fn ln(self) -> Self {
-        DualNumber::new(self.real().ln(), self.dual() / self.real())
+        self.ln_parts(self.real().ln())
     }
 
+    /// Logarithm in an arbitrary base, `ln(x) / ln(base)`.
+    ///
+    /// Both `self` and `base` may carry a derivative: the dual part is
+    /// `x' / (x ln(base)) - ln(x) base' / (base ln²(base))`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(5.0f64, 1.0);
+    /// let base = DualNumber::new(3.0f64, 0.5);
+    /// let y = x.log(base);
+    ///
+    /// let (ln_x, ln_b) = (5f64.ln(), 3f64.ln());
+    /// assert!((y.real() - ln_x / ln_b).abs() < 1e-15);
+    /// assert!((y.dual() - (1.0 / (5.0 * ln_b) - ln_x * 0.5 / (3.0 * ln_b * ln_b))).abs() < 1e-15);
+    ///
+    /// // log2 and log10 agree with a constant base
+    /// for &b in &[2.0f64, 10.0] {
+    ///     let y = x.log(DualNumber::from_real(b));
+    ///     let z = if b == 2.0 { x.log2() } else { x.log10() };
+    ///
+    ///     assert!((y.real() - z.real()).abs() < 1e-15);
+    ///     assert!((y.dual() - z.dual()).abs() < 1e-15);
+    /// }
+    /// ```
     #[inline]
     fn log(self, base: Self) -> Self {
         self.ln() / base.ln()
@@ -564,7 +3061,7 @@ impl<T> Float for DualNumber<T> where T: Float + Signed + FloatConst {
 
     #[inline]
     fn log2(self) -> Self {
-        DualNumber::new(self.real().log10(), self.dual() / (self.real() * T::LN_2()))
+        DualNumber::new(self.real().log2(), self.dual() / (self.real() * T::LN_2()))
     }
 
     #[inline]
@@ -572,28 +3069,131 @@ impl<T> Float for DualNumber<T> where T: Float + Signed + FloatConst {
         DualNumber::new(self.real().log10(), self.dual() / (self.real() * T::LN_10()))
     }
 
+    /// Square root, with derivative `dual / (2 sqrt(real))`.
+    ///
+    /// At `real == 0` the derivative of `sqrt` is singular, so a nonzero dual part
+    /// becomes a signed infinity (and a zero dual part becomes NaN).
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).sqrt().into_tuple(), (0.0, f64::INFINITY));
+    /// assert_eq!(DualNumber::new(0.0f64, -1.0).sqrt().into_tuple(), (0.0, f64::NEG_INFINITY));
+    /// assert_eq!(DualNumber::new(4.0f64, 1.0).sqrt().into_tuple(), (2.0, 0.25));
+    /// ```
+    ///
+    /// The constants in the derivatives of `sqrt` and `cbrt` are built from `T::one()`, so they work
+    /// for part types that cannot convert from integers:
+    ///
+    /// ```rust
+    /// # extern crate num_traits;
+    /// # extern crate dual_num;
+    /// # use dual_num::{DualNumber, Float, FloatConst, Num, One, Zero};
+    /// # use num_traits::{NumCast, Signed, ToPrimitive};
+    /// # use std::num::FpCategory;
+    /// # use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+    /// /// An `f64` whose conversions from other numbers always fail
+    /// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    /// struct NoCast(f64);
+    ///
+    /// impl NumCast for NoCast {
+    ///     fn from<N: ToPrimitive>(_: N) -> Option<Self> { None }
+    /// }
+    /// # macro_rules! binops { ($($tr:ident $m:ident),*) => {$(
+    /// #     impl $tr for NoCast { type Output = NoCast; fn $m(self, o: NoCast) -> NoCast { NoCast(self.0.$m(o.0)) } }
+    /// # )*} }
+    /// # binops!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+    /// # impl Neg for NoCast { type Output = NoCast; fn neg(self) -> NoCast { NoCast(-self.0) } }
+    /// # impl Zero for NoCast { fn zero() -> Self { NoCast(0.0) } fn is_zero(&self) -> bool { self.0 == 0.0 } }
+    /// # impl One for NoCast { fn one() -> Self { NoCast(1.0) } }
+    /// # impl Num for NoCast {
+    /// #     type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+    /// #     fn from_str_radix(s: &str, r: u32) -> Result<Self, Self::FromStrRadixErr> { f64::from_str_radix(s, r).map(NoCast) }
+    /// # }
+    /// # impl ToPrimitive for NoCast {
+    /// #     fn to_i64(&self) -> Option<i64> { self.0.to_i64() }
+    /// #     fn to_u64(&self) -> Option<u64> { self.0.to_u64() }
+    /// # }
+    /// # impl Signed for NoCast {
+    /// #     fn abs(&self) -> Self { NoCast(self.0.abs()) }
+    /// #     fn abs_sub(&self, o: &Self) -> Self { NoCast(Signed::abs_sub(&self.0, &o.0)) }
+    /// #     fn signum(&self) -> Self { NoCast(Signed::signum(&self.0)) }
+    /// #     fn is_positive(&self) -> bool { self.0 > 0.0 }
+    /// #     fn is_negative(&self) -> bool { self.0 < 0.0 }
+    /// # }
+    /// # macro_rules! consts { ($($c:ident),*) => { impl FloatConst for NoCast { $(fn $c() -> Self { NoCast(f64::$c()) })* } } }
+    /// # consts!(E, FRAC_1_PI, FRAC_1_SQRT_2, FRAC_2_PI, FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6,
+    /// #         FRAC_PI_8, LN_10, LN_2, LOG10_E, LOG2_E, PI, SQRT_2);
+    /// # macro_rules! float {
+    /// #     (consts $($c:ident)*; preds $($p:ident)*; unary $($u:ident)*; binary $($b:ident)*) => {
+    /// #         $(fn $c() -> Self { NoCast(<f64 as Float>::$c()) })*
+    /// #         $(fn $p(self) -> bool { Float::$p(self.0) })*
+    /// #         $(fn $u(self) -> Self { NoCast(Float::$u(self.0)) })*
+    /// #         $(fn $b(self, o: Self) -> Self { NoCast(Float::$b(self.0, o.0)) })*
+    /// #     }
+    /// # }
+    /// # impl Float for NoCast {
+    /// #     float!(consts nan infinity neg_infinity neg_zero min_value min_positive_value max_value;
+    /// #            preds is_nan is_infinite is_finite is_normal is_sign_positive is_sign_negative;
+    /// #            unary floor ceil round trunc fract abs signum recip sqrt exp exp2 ln log2 log10 cbrt sin cos tan
+    /// #                  asin acos atan exp_m1 ln_1p sinh cosh tanh asinh acosh atanh;
+    /// #            binary powf log max min abs_sub hypot atan2);
+    /// #     fn classify(self) -> FpCategory { self.0.classify() }
+    /// #     fn mul_add(self, a: Self, b: Self) -> Self { NoCast(self.0.mul_add(a.0, b.0)) }
+    /// #     fn powi(self, n: i32) -> Self { NoCast(self.0.powi(n)) }
+    /// #     fn sin_cos(self) -> (Self, Self) { let (s, c) = self.0.sin_cos(); (NoCast(s), NoCast(c)) }
+    /// #     fn integer_decode(self) -> (u64, i16, i8) { Float::integer_decode(self.0) }
+    /// # }
+    ///
+    /// // ... with Float, Signed and FloatConst implemented by forwarding to the f64
+    /// # fn main() {
+    /// assert!(<NoCast as NumCast>::from(2).is_none());
+    ///
+    /// let x = DualNumber::new(NoCast(4.0), NoCast(1.0));
+    /// assert_eq!(x.sqrt().into_tuple(), (NoCast(2.0), NoCast(0.25)));
+    /// assert_eq!(DualNumber::new(NoCast(-8.0), NoCast(1.0)).cbrt().into_tuple(), (NoCast(-2.0), NoCast(1.0 / 12.0)));
+    /// # }
+    /// ```
     #[inline]
     fn sqrt(self) -> Self {
-        let real = self.real().sqrt();
-
-        DualNumber::new(real, self.dual() / (T::from(2).unwrap() * real))
+        self.sqrt_parts(self.real().sqrt())
     }
 
+    /// Cube root, with derivative `dual / (3 cbrt(real)²)`.
+    ///
+    /// As with `sqrt`, at `real == 0` a nonzero dual part becomes a signed infinity.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).cbrt().into_tuple(), (0.0, f64::INFINITY));
+    /// assert_eq!(DualNumber::new(0.0f64, -1.0).cbrt().into_tuple(), (0.0, f64::NEG_INFINITY));
+    /// assert_eq!(DualNumber::new(-8.0f64, 1.0).cbrt().into_tuple(), (-2.0, 1.0 / 12.0));
+    /// ```
     #[inline]
     fn cbrt(self) -> Self {
         let real = self.real().cbrt();
 
-        DualNumber::new(real, self.dual() / (T::from(3).unwrap() * real))
+        let three = T::one() + T::one() + T::one();
+
+        DualNumber::new(real, self.dual() / (three * real * real))
     }
 
+    /// Length of the hypotenuse, with derivative `(x x' + y y') / hypot(x, y)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let h = DualNumber::new(3.0f64, 1.0).hypot(DualNumber::new(4.0, 0.5));
+    ///
+    /// assert_eq!(h.into_tuple(), (5.0, (3.0 + 2.0) / 5.0));
+    /// assert_eq!(DualNumber::from_real(1.0f64).hypot(DualNumber::new(0.0, 1.0)).into_tuple(), (1.0, 0.0));
+    /// ```
     fn hypot(self, other: Self) -> Self {
         let real = self.real().hypot(other.real());
 
-        DualNumber::new(real, (self.real() * other.dual() + other.real() * self.dual()) / real)
+        DualNumber::new(real, (self.real() * self.dual() + other.real() * other.dual()) / real)
     }
 
-    fn sin(self) -> Self { DualNumber::new(self.real().sin(), self.dual() * self.real().cos()) }
-    fn cos(self) -> Self { DualNumber::new(self.real().cos(), self.dual().neg() * self.real().sin()) }
+    fn sin(self) -> Self { self.sin_cos().0 }
+    fn cos(self) -> Self { self.sin_cos().1 }
 
     fn tan(self) -> Self {
         let t = self.real().tan();
@@ -601,25 +3201,66 @@ impl<T> Float for DualNumber<T> where T: Float + Signed + FloatConst {
         DualNumber::new(t, self.dual() * (t * t + T::one()))
     }
 
-    fn asin(self) -> Self { DualNumber::new(self.real().asin(), self.dual() / (T::one() - self.real().powi(2)).sqrt()) }
-    fn acos(self) -> Self { DualNumber::new(self.real().acos(), self.dual().neg() / (T::one() - self.real().powi(2)).sqrt()) }
-    fn atan(self) -> Self { DualNumber::new(self.real().atan(), self.dual() / (self.real().powi(2) + T::one()).sqrt()) }
+    /// Inverse sine, with derivative `1/√(1 - x²)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for i in -20..=20 {
+    ///     let v = i as f64 / 20.0 * 0.999;
+    ///     let (s, c) = (DualNumber::new(v, 1.5).asin(), DualNumber::new(v, 1.5).acos());
+    ///     let root = (1.0 - v.powi(2)).sqrt();
+    ///
+    ///     assert_eq!(s.into_tuple(), (v.asin(), 1.5 / root));
+    ///     assert_eq!(c.into_tuple(), (v.acos(), -1.5 / root));
+    /// }
+    /// ```
+    fn asin(self) -> Self { DualNumber::new(self.real().asin(), self.dual() / one_minus_sq_sqrt(self.real())) }
+
+    /// Inverse cosine, with derivative `-1/√(1 - x²)`.
+    fn acos(self) -> Self { DualNumber::new(self.real().acos(), self.dual().neg() / one_minus_sq_sqrt(self.real())) }
+
+    /// Inverse tangent, with derivative `1/(1 + x²)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// assert_eq!(DualNumber::new(1.0f64, 1.0).atan().into_tuple(), (std::f64::consts::FRAC_PI_4, 0.5));
+    ///
+    /// // Round trip through tan over the principal range recovers both parts
+    /// for &v in &[-1.5f64, -0.7, 0.0, 0.2, 1.0, 1.5] {
+    ///     let x = DualNumber::new(v, 1.0);
+    ///     let y = x.tan().atan();
+    ///
+    ///     assert!((y.real() - v).abs() < 1e-15);
+    ///     assert!((y.dual() - 1.0).abs() < 1e-13);
+    /// }
+    /// ```
+    fn atan(self) -> Self {
+        DualNumber::new(self.real().atan(), self.dual() / (self.real() * self.real() + T::one()))
+    }
 
+    /// Four quadrant inverse tangent of `self / other`, with dual part `(x y' - y x') / (x² + y²)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for i in 0..16 {
+    ///     let t = i as f64 * 0.4 - 3.0;
+    ///     let (y, x) = (DualNumber::new(t.sin() * 2.0, 0.5), DualNumber::new(t.cos() * 2.0, -1.0));
+    ///     let a = y.atan2(x);
+    ///
+    ///     assert_eq!(a.real(), y.real().atan2(x.real()));
+    ///     assert_eq!(a.dual(), (x.real() * 0.5 + y.real()) / (y.real().powi(2) + x.real().powi(2)));
+    /// }
+    /// ```
     fn atan2(self, other: Self) -> Self {
-        DualNumber::new(
-            self.real().atan2(other.real()),
-            (other.real() * self.dual() - self.real() * other.dual()) /
-                (self.real().powi(2) + other.real().powi(2))
-        )
+        let (y, x) = (self.real(), other.real());
+
+        DualNumber::new(y.atan2(x), (x * self.dual() - y * other.dual()) / (y * y + x * x))
     }
 
     fn sin_cos(self) -> (Self, Self) {
         let (s, c) = self.real().sin_cos();
 
-        let sn = DualNumber::new(s, self.dual() * c);
-        let cn = DualNumber::new(c, self.dual().neg() * s);
-
-        (sn, cn)
+        self.sin_cos_parts(s, c)
     }
 
     fn exp_m1(self) -> Self { DualNumber::new(self.real().exp_m1(), self.dual() * self.real().exp()) }
@@ -630,12 +3271,22 @@ impl<T> Float for DualNumber<T> where T: Float + Signed + FloatConst {
     fn cosh(self) -> Self { DualNumber::new(self.real().cosh(), self.dual() * self.real().sinh()) }
 
     fn tanh(self) -> Self {
-        let real = self.real().tanh();
-
-        DualNumber::new(real, self.dual() * (T::one() - real.powi(2)))
+        self.tanh_parts(self.real().tanh())
     }
 
-    fn asinh(self) -> Self { DualNumber::new(self.real().asinh(), self.dual() / (self.real().powi(2) + T::one()).sqrt()) }
+    /// Inverse hyperbolic sine, with derivative `1/√(x² + 1)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// for i in -20..=20 {
+    ///     let v = i as f64 / 7.0;
+    ///
+    ///     assert_eq!(DualNumber::new(v, 2.0).asinh().dual(), 2.0 / (v.powi(2) + 1.0).sqrt());
+    ///     assert_eq!(DualNumber::new(v / 3.0, 2.0).atanh().dual(), 2.0 / (1.0 - (v / 3.0).powi(2)));
+    ///     assert_eq!(DualNumber::new(v, 2.0).tanh().dual(), 2.0 * (1.0 - v.tanh().powi(2)));
+    /// }
+    /// ```
+    fn asinh(self) -> Self { DualNumber::new(self.real().asinh(), self.dual() / (self.real() * self.real() + T::one()).sqrt()) }
 
     fn acosh(self) -> Self {
         DualNumber::new(self.real().acosh(),
@@ -644,14 +3295,32 @@ impl<T> Float for DualNumber<T> where T: Float + Signed + FloatConst {
                                 (self.real() - T::one()).sqrt()))
     }
 
-    fn atanh(self) -> Self { DualNumber::new(self.real().atanh(), self.dual() / (T::one() - self.real().powi(2))) }
+    fn atanh(self) -> Self { DualNumber::new(self.real().atanh(), self.dual() / (T::one() - self.real() * self.real())) }
 
     #[inline]
     fn integer_decode(self) -> (u64, i16, i8) { self.real().integer_decode() }
 
+    /// Converts radians to degrees, scaling the dual part by the same `180/π` factor.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// let x = DualNumber::new(f64::PI(), 1.0).to_degrees();
+    ///
+    /// assert_eq!(x.real(), 180.0);
+    /// assert_eq!(x.dual(), 1.0f64.to_degrees());
+    /// ```
     #[inline]
-    fn to_degrees(self) -> Self { DualNumber::from_real(self.real().to_degrees()) }
+    fn to_degrees(self) -> Self { DualNumber::new(self.real().to_degrees(), self.dual().to_degrees()) }
 
+    /// Converts degrees to radians, scaling the dual part by the same `π/180` factor.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// let x = DualNumber::new(180.0f64, 1.0).to_radians();
+    ///
+    /// assert_eq!(x.real(), f64::PI());
+    /// assert_eq!(x.dual(), 1.0f64.to_radians());
+    /// ```
     #[inline]
-    fn to_radians(self) -> Self { DualNumber::from_real(self.real().to_radians()) }
+    fn to_radians(self) -> Self { DualNumber::new(self.real().to_radians(), self.dual().to_radians()) }
 }
\ No newline at end of file
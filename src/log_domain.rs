@@ -0,0 +1,249 @@
+//! Log-domain sums and the softmax
+//!
+//! These work with `exp(xᵢ)` with the largest term factored out, so that neither part
+//! overflows when the inputs span hundreds of orders of magnitude.
+
+use super::{DualNumber, Float, FloatConst};
+
+use num_traits::Signed;
+
+/// Weight of a term relative to the running maximum, `exp(x - max)`.
+///
+/// Terms equal to the maximum have a weight of exactly one, which also holds when the maximum is infinite.
+#[inline]
+fn weight<T: Float>(x: T, max: T) -> T {
+    if x == max { T::one() } else { (x - max).exp() }
+}
+
+/// Computes `ln(exp(a) + exp(b))` without overflow.
+///
+/// The dual part is the weighted average of the dual parts of `a` and `b`, with the softmax weights
+/// `exp(a) / (exp(a) + exp(b))` and `exp(b) / (exp(a) + exp(b))`. If both are `-∞`, the result is `-∞`
+/// with a zero dual part.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, logaddexp};
+/// let (a, b) = (DualNumber::new(0.5f64, 1.0), DualNumber::new(-0.25f64, 0.0));
+/// let naive = (a.exp() + b.exp()).ln();
+/// let y = logaddexp(a, b);
+///
+/// assert!((y.real() - naive.real()).abs() < 1e-15);
+/// assert!((y.dual() - naive.dual()).abs() < 1e-15);
+/// assert!((y.dual() - 1.0 / (1.0 + (-0.75f64).exp())).abs() < 1e-15);
+///
+/// // Exponents a thousand apart
+/// let y = logaddexp(DualNumber::new(1000.0f64, 1.0), DualNumber::new(0.0, 1.0));
+/// assert_eq!(y.into_tuple(), (1000.0, 1.0));
+/// let y = logaddexp(DualNumber::new(1000.0f64, 2.0), DualNumber::new(1000.0, 0.0));
+/// assert_eq!(y.into_tuple(), (1000.0 + 2f64.ln(), 1.0));
+///
+/// let inf = DualNumber::new(f64::NEG_INFINITY, 1.0);
+/// assert_eq!(logaddexp(inf, DualNumber::new(2.0, 3.0)).into_tuple(), (2.0, 3.0));
+/// assert_eq!(logaddexp(inf, inf).into_tuple(), (f64::NEG_INFINITY, 0.0));
+/// ```
+pub fn logaddexp<T>(a: DualNumber<T>, b: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let mut acc = LogSumExpAccumulator::new();
+
+    acc.push(a);
+    acc.push(b);
+    acc.result()
+}
+
+/// Computes `ln(Σ exp(xᵢ))` over a slice in two passes, first finding the maximum and then summing
+/// the terms relative to it.
+///
+/// The dual part is `Σ softmax(x)ᵢ xᵢ'`. An empty slice, or one where every real part is `-∞`,
+/// gives `-∞` with a zero dual part.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, logsumexp};
+/// let x = [1.0f64, 2.0, 3.0];
+/// let total: f64 = x.iter().map(|v| v.exp()).sum();
+///
+/// // The gradient is the softmax of the inputs
+/// for i in 0..3 {
+///     let seeded: Vec<_> = x.iter().enumerate()
+///         .map(|(j, &v)| DualNumber::new(v, if i == j { 1.0 } else { 0.0 }))
+///         .collect();
+///     let y = logsumexp(&seeded);
+///
+///     assert!((y.real() - total.ln()).abs() < 1e-15);
+///     assert!((y.dual() - x[i].exp() / total).abs() < 1e-15);
+/// }
+///
+/// // Spreads where the naive sum overflows or underflows
+/// let wide = [DualNumber::new(1000.0f64, 1.0), DualNumber::new(0.0, 5.0), DualNumber::new(-1000.0, 5.0)];
+/// assert_eq!(logsumexp(&wide).into_tuple(), (1000.0, 1.0));
+/// let low = [DualNumber::new(-1000.0f64, 1.0), DualNumber::new(-1000.0, 3.0)];
+/// assert_eq!(logsumexp(&low).into_tuple(), (-1000.0 + 2f64.ln(), 2.0));
+///
+/// let inf = DualNumber::new(f64::NEG_INFINITY, 1.0);
+/// assert_eq!(logsumexp(&[inf, inf]).into_tuple(), (f64::NEG_INFINITY, 0.0));
+/// assert_eq!(logsumexp::<f64>(&[]).into_tuple(), (f64::NEG_INFINITY, 0.0));
+/// ```
+pub fn logsumexp<T>(x: &[DualNumber<T>]) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let max = x.iter().fold(T::neg_infinity(), |max, v| max.max(v.real()));
+
+    if max == T::neg_infinity() {
+        return DualNumber::new(max, T::zero());
+    }
+
+    let (sum, dual) = x.iter().fold((T::zero(), T::zero()), |(sum, dual), v| {
+        let w = weight(v.real(), max);
+
+        (sum + w, dual + w * v.dual())
+    });
+
+    DualNumber::new(max + sum.ln(), dual / sum)
+}
+
+/// Streaming version of `logsumexp`, which rescales its sums whenever a new maximum is pushed.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, LogSumExpAccumulator, logsumexp};
+/// let x: Vec<_> = (0..50).map(|i| DualNumber::new((i as f64 * 37.0) % 101.0 - 50.0, i as f64)).collect();
+///
+/// let mut acc = LogSumExpAccumulator::new();
+/// for &v in &x {
+///     acc.push(v);
+/// }
+///
+/// let (streamed, batch) = (acc.result(), logsumexp(&x));
+/// assert!((streamed.real() - batch.real()).abs() < 1e-13);
+/// assert!((streamed.dual() - batch.dual()).abs() < 1e-12);
+///
+/// assert_eq!(LogSumExpAccumulator::<f64>::new().result().into_tuple(), (f64::NEG_INFINITY, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LogSumExpAccumulator<T> {
+    max: T,
+    sum: T,
+    dual: T,
+}
+
+impl<T> LogSumExpAccumulator<T> where T: Float + Signed + FloatConst {
+    /// Creates an empty accumulator, whose result is `-∞`.
+    #[inline]
+    pub fn new() -> LogSumExpAccumulator<T> {
+        LogSumExpAccumulator { max: T::neg_infinity(), sum: T::zero(), dual: T::zero() }
+    }
+
+    /// Adds the term `exp(x)` to the sum.
+    pub fn push(&mut self, x: DualNumber<T>) {
+        if x.real() > self.max {
+            let scale = weight(self.max, x.real());
+
+            self.sum = self.sum * scale;
+            self.dual = self.dual * scale;
+            self.max = x.real();
+        }
+
+        if self.max == T::neg_infinity() {
+            return;
+        }
+
+        let w = weight(x.real(), self.max);
+
+        self.sum = self.sum + w;
+        self.dual = self.dual + w * x.dual();
+    }
+
+    /// Returns `ln(Σ exp(xᵢ))` over the terms pushed so far.
+    pub fn result(&self) -> DualNumber<T> {
+        if self.max == T::neg_infinity() {
+            return DualNumber::new(self.max, T::zero());
+        }
+
+        DualNumber::new(self.max + self.sum.ln(), self.dual / self.sum)
+    }
+}
+
+impl<T> Default for LogSumExpAccumulator<T> where T: Float + Signed + FloatConst {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the softmax of `x`, `exp(xᵢ) / Σ exp(xⱼ)`, subtracting the largest real part first so
+/// that large inputs do not overflow.
+///
+/// The dual parts form the Jacobian-vector product with the dual parts of the inputs,
+/// `yᵢ (xᵢ' - Σ yⱼ xⱼ')`. See `softmax_into` to write into an existing buffer.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, softmax};
+/// let logits = [1.0f64, -0.5, 2.0, 0.25];
+/// let seeds = [0.3f64, -1.0, 0.5, 2.0];
+/// let x: Vec<_> = logits.iter().zip(&seeds).map(|(&l, &s)| DualNumber::new(l, s)).collect();
+/// let y = softmax(&x);
+///
+/// assert!((y.iter().map(|y| y.real()).sum::<f64>() - 1.0).abs() < 1e-15);
+/// assert!(y.iter().map(|y| y.dual()).sum::<f64>().abs() < 1e-15);
+///
+/// // Invariant under a common shift of the logits
+/// let shifted: Vec<_> = x.iter().map(|&x| x + 100.0).collect();
+/// for (a, b) in y.iter().zip(softmax(&shifted)) {
+///     assert!((a.real() - b.real()).abs() < 1e-15);
+///     assert!((a.dual() - b.dual()).abs() < 1e-14);
+/// }
+///
+/// // Finite difference along the seed direction
+/// let h = 1e-6;
+/// let plain = |t: f64| {
+///     let e: Vec<f64> = logits.iter().zip(&seeds).map(|(l, s)| (l + t * s).exp()).collect();
+///     let total: f64 = e.iter().sum();
+///     e.into_iter().map(|e| e / total).collect::<Vec<_>>()
+/// };
+/// let (up, down) = (plain(h), plain(-h));
+/// for i in 0..4 {
+///     assert!((y[i].dual() - (up[i] - down[i]) / (2.0 * h)).abs() < 1e-9);
+/// }
+///
+/// // Huge logits do not overflow
+/// let y = softmax(&[DualNumber::new(1e4f64, 1.0), DualNumber::new(1e4 - 1.0, 0.0)]);
+/// let p = 1.0 / (1.0 + (-1f64).exp());
+/// assert!((y[0].real() - p).abs() < 1e-15);
+/// assert!((y[0].dual() - p * (1.0 - p)).abs() < 1e-15);
+/// ```
+pub fn softmax<T>(x: &[DualNumber<T>]) -> Vec<DualNumber<T>> where T: Float + Signed + FloatConst {
+    let mut out = vec![DualNumber::from_real(T::zero()); x.len()];
+
+    softmax_into(x, &mut out);
+    out
+}
+
+/// Like `softmax`, but writes the result into `out` instead of allocating.
+///
+/// If every real part is `-∞`, the result is uniform.
+///
+/// # Panics
+///
+/// Panics if `out` and `x` have different lengths.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, softmax, softmax_into};
+/// let x = [DualNumber::new(0.5f64, 1.0), DualNumber::new(-1.0, 0.0), DualNumber::new(3.0, 2.0)];
+/// let mut out = [DualNumber::from_real(0.0); 3];
+///
+/// softmax_into(&x, &mut out);
+/// assert_eq!(out.to_vec(), softmax(&x));
+/// ```
+pub fn softmax_into<T>(x: &[DualNumber<T>], out: &mut [DualNumber<T>]) where T: Float + Signed + FloatConst {
+    assert_eq!(x.len(), out.len(), "softmax output length does not match the input");
+
+    let max = x.iter().fold(T::neg_infinity(), |max, v| max.max(v.real()));
+
+    let (sum, dual) = x.iter().fold((T::zero(), T::zero()), |(sum, dual), v| {
+        let w = weight(v.real(), max);
+
+        (sum + w, dual + w * v.dual())
+    });
+
+    let mean = dual / sum;
+
+    for (y, v) in out.iter_mut().zip(x) {
+        let p = weight(v.real(), max) / sum;
+
+        *y = DualNumber::new(p, p * (v.dual() - mean));
+    }
+}
@@ -0,0 +1,401 @@
+//! Loss functions
+//!
+//! Fallible losses report invalid arguments with a `DualError` instead of panicking.
+
+use super::{DualError, DualNumber, Float, FloatConst, Zero, logsumexp};
+
+use num_traits::Signed;
+
+/// Checks that `target` indexes a slice of length `len`.
+fn check_target(target: usize, len: usize) -> Result<(), DualError> {
+    if target < len { Ok(()) } else { Err(DualError::TargetOutOfRange { target, len }) }
+}
+
+/// Cross-entropy of the softmax of `logits` against the class `target`, `logsumexp(logits) - logits[target]`.
+///
+/// The probabilities are never materialized, so this is stable for extreme logits.
+/// Seeding the logits gives the gradient `softmax(logits) - onehot(target)`.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber, Float, cross_entropy, softmax};
+/// let logits = [0.5f64, 2.0, -1.0];
+/// let p = softmax(&logits.iter().map(|&l| DualNumber::from_real(l)).collect::<Vec<_>>());
+///
+/// for i in 0..3 {
+///     let seeded: Vec<_> = logits.iter().enumerate()
+///         .map(|(j, &l)| DualNumber::new(l, if i == j { 1.0 } else { 0.0 }))
+///         .collect();
+///     let loss = cross_entropy(&seeded, 1).unwrap();
+///
+///     assert!((loss.real() + p[1].real().ln()).abs() < 1e-15);
+///     assert!((loss.dual() - (p[i].real() - if i == 1 { 1.0 } else { 0.0 })).abs() < 1e-15);
+///
+///     // Invariant under a common shift of the logits
+///     let shifted: Vec<_> = seeded.iter().map(|&l| l + 50.0).collect();
+///     let moved = cross_entropy(&shifted, 1).unwrap();
+///     assert!((moved.real() - loss.real()).abs() < 1e-13);
+///     assert!((moved.dual() - loss.dual()).abs() < 1e-15);
+/// }
+///
+/// // Extreme logits
+/// let extreme = [DualNumber::new(1e4f64, 1.0), DualNumber::from_real(0.0), DualNumber::from_real(-1e4)];
+/// assert_eq!(cross_entropy(&extreme, 2).unwrap().into_tuple(), (2e4, 1.0));
+/// assert_eq!(cross_entropy(&extreme, 0).unwrap().into_tuple(), (0.0, 0.0));
+///
+/// assert_eq!(cross_entropy(&extreme, 3).unwrap_err(), DualError::TargetOutOfRange { target: 3, len: 3 });
+/// ```
+pub fn cross_entropy<T>(logits: &[DualNumber<T>], target: usize) -> Result<DualNumber<T>, DualError>
+    where T: Float + Signed + FloatConst {
+    check_target(target, logits.len())?;
+
+    Ok(logsumexp(logits) - logits[target])
+}
+
+/// Cross-entropy of the softmax of `logits` against the soft target distribution `target`,
+/// `Σ pᵢ (logsumexp(logits) - logitsᵢ)`.
+///
+/// Classes with zero probability are skipped, so they may have logits of `-∞`. A one-hot `target`
+/// reduces to `cross_entropy`.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber, cross_entropy, cross_entropy_soft};
+/// let logits = [DualNumber::new(0.5f64, 1.0), DualNumber::new(2.0, 0.0), DualNumber::new(-1.0, 0.0)];
+///
+/// let soft = cross_entropy_soft(&logits, &[0.0, 1.0, 0.0]).unwrap();
+/// let hard = cross_entropy(&logits, 1).unwrap();
+/// assert_eq!(soft.into_tuple(), hard.into_tuple());
+///
+/// // Linear in the target distribution
+/// let mixed = cross_entropy_soft(&logits, &[0.25, 0.75, 0.0]).unwrap();
+/// let other = cross_entropy(&logits, 0).unwrap();
+/// assert!((mixed.real() - (0.25 * other.real() + 0.75 * hard.real())).abs() < 1e-15);
+/// assert!((mixed.dual() - (0.25 * other.dual() + 0.75 * hard.dual())).abs() < 1e-15);
+///
+/// assert_eq!(cross_entropy_soft(&logits, &[1.0]).unwrap_err(), DualError::LengthMismatch { expected: 3, found: 1 });
+/// ```
+pub fn cross_entropy_soft<T>(logits: &[DualNumber<T>], target: &[T]) -> Result<DualNumber<T>, DualError>
+    where T: Float + Signed + FloatConst {
+    if logits.len() != target.len() {
+        return Err(DualError::LengthMismatch { expected: logits.len(), found: target.len() });
+    }
+
+    let lse = logsumexp(logits);
+
+    Ok(logits.iter().zip(target).filter(|&(_, p)| !p.is_zero())
+        .fold(DualNumber::zero(), |acc, (&l, &p)| acc + (lse - l) * p))
+}
+
+/// Negative log-likelihood of the class `target`, `-log_probs[target]`, given log-probabilities
+/// such as the output of a log-softmax.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber, nll_loss};
+/// let log_probs = [DualNumber::new(-0.5f64, 1.0), DualNumber::new(-1.5, 2.0)];
+///
+/// assert_eq!(nll_loss(&log_probs, 1).unwrap().into_tuple(), (1.5, -2.0));
+/// assert_eq!(nll_loss(&log_probs, 2).unwrap_err(), DualError::TargetOutOfRange { target: 2, len: 2 });
+/// ```
+pub fn nll_loss<T>(log_probs: &[DualNumber<T>], target: usize) -> Result<DualNumber<T>, DualError>
+    where T: Float + Signed + FloatConst {
+    check_target(target, log_probs.len())?;
+
+    Ok(-log_probs[target])
+}
+
+/// Binary cross-entropy of `sigmoid(z)` against the label `y`, computed from the logit `z` as
+/// `max(z, 0) - z y + ln(1 + e^(-|z|))`.
+///
+/// This never evaluates `ln(sigmoid(z))` directly, so it does not overflow for large `|z|`.
+/// The dual part is `(sigmoid(z) - y) z'`, with the sigmoid also evaluated stably.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, bce_with_logits};
+/// let sigmoid = |z: f64| 1.0 / (1.0 + (-z).exp());
+///
+/// for &z in &[-8.0f64, -1.5, -0.2, 0.0, 0.7, 3.0, 8.0] {
+///     for &y in &[0.0f64, 0.3, 1.0] {
+///         let l = bce_with_logits(DualNumber::new(z, 1.0), y);
+///         let s = sigmoid(z);
+///
+///         assert!((l.dual() - (s - y)).abs() < 1e-15);
+///         assert!((l.real() - (-y * s.ln() - (1.0 - y) * (1.0 - s).ln())).abs() < 1e-12);
+///     }
+/// }
+///
+/// // Saturated logits neither overflow nor lose the gradient
+/// assert_eq!(bce_with_logits(DualNumber::new(100.0f64, 1.0), 0.0).into_tuple(), (100.0, 1.0));
+/// assert_eq!(bce_with_logits(DualNumber::new(-100.0f64, 1.0), 1.0).into_tuple(), (100.0, -1.0));
+/// assert!(bce_with_logits(DualNumber::new(100.0f64, 1.0), 1.0).real() < 1e-40);
+/// ```
+pub fn bce_with_logits<T>(z: DualNumber<T>, y: T) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let x = z.real();
+    let e = (-x.abs()).exp();
+
+    let sigmoid = if x >= T::zero() { (T::one() + e).recip() } else { e / (T::one() + e) };
+    let real = x.max(T::zero()) - x * y + e.ln_1p();
+
+    DualNumber::new(real, (sigmoid - y) * z.dual())
+}
+
+/// Mean of `bce_with_logits` over pairs of logits and labels.
+///
+/// An empty slice gives a NaN mean.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber, bce_with_logits, bce_with_logits_mean};
+/// let z = [DualNumber::new(0.5f64, 1.0), DualNumber::new(-2.0, 0.5), DualNumber::new(4.0, 0.0)];
+/// let y = [1.0, 0.0, 0.25];
+///
+/// let mean = bce_with_logits_mean(&z, &y).unwrap();
+/// let sum = (0..3).fold(DualNumber::from_real(0.0), |acc, i| acc + bce_with_logits(z[i], y[i]));
+///
+/// assert!((mean.real() - sum.real() / 3.0).abs() < 1e-15);
+/// assert!((mean.dual() - sum.dual() / 3.0).abs() < 1e-15);
+///
+/// assert_eq!(bce_with_logits_mean(&z, &y[..2]).unwrap_err(), DualError::LengthMismatch { expected: 3, found: 2 });
+/// ```
+pub fn bce_with_logits_mean<T>(z: &[DualNumber<T>], y: &[T]) -> Result<DualNumber<T>, DualError>
+    where T: Float + Signed + FloatConst {
+    check_lengths(z, y)?;
+
+    let sum = z.iter().zip(y).fold(DualNumber::zero(), |acc, (&z, &y)| acc + bce_with_logits(z, y));
+
+    Ok(sum / T::from(z.len()).unwrap())
+}
+
+/// Sums both parts with Neumaier's compensated summation.
+fn compensated_sum<T, I>(terms: I) -> DualNumber<T> where T: Float, I: Iterator<Item = DualNumber<T>> {
+    fn add<T: Float>(sum: &mut T, comp: &mut T, x: T) {
+        let t = *sum + x;
+
+        *comp = *comp + if sum.abs() >= x.abs() { (*sum - t) + x } else { (x - t) + *sum };
+        *sum = t;
+    }
+
+    let (mut real, mut real_comp) = (T::zero(), T::zero());
+    let (mut dual, mut dual_comp) = (T::zero(), T::zero());
+
+    for x in terms {
+        add(&mut real, &mut real_comp, x.real());
+        add(&mut dual, &mut dual_comp, x.dual());
+    }
+
+    DualNumber::new(real + real_comp, dual + dual_comp)
+}
+
+/// Checks that predictions and targets have the same length.
+fn check_lengths<T>(pred: &[DualNumber<T>], target: &[T]) -> Result<(), DualError> {
+    if pred.len() == target.len() {
+        Ok(())
+    } else {
+        Err(DualError::LengthMismatch { expected: pred.len(), found: target.len() })
+    }
+}
+
+/// Sum of squared errors, `Σ (predᵢ - targetᵢ)²`, accumulated with compensated summation.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber, mse_sum};
+/// let pred = [DualNumber::new(1.0f64, 1.0), DualNumber::new(2.5, 0.0)];
+///
+/// assert_eq!(mse_sum(&pred, &[0.5, 3.0]).unwrap().into_tuple(), (0.5, 1.0));
+/// assert_eq!(mse_sum(&pred, &[0.5]).unwrap_err(), DualError::LengthMismatch { expected: 2, found: 1 });
+/// ```
+pub fn mse_sum<T>(pred: &[DualNumber<T>], target: &[T]) -> Result<DualNumber<T>, DualError>
+    where T: Float + Signed + FloatConst {
+    check_lengths(pred, target)?;
+
+    Ok(compensated_sum(pred.iter().zip(target).map(|(&p, &t)| {
+        let r = p - t;
+
+        r * r
+    })))
+}
+
+/// Mean squared error, `(1/n) Σ (predᵢ - targetᵢ)²`, accumulated with compensated summation.
+///
+/// The dual part is `(2/n) Σ (predᵢ - targetᵢ) predᵢ'`. Empty slices give a NaN mean.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber, mse};
+/// let values = [1.0f64, -0.5, 2.0, 0.25];
+/// let seeds = [0.5f64, 1.0, -2.0, 0.0];
+/// let target = [0.5f64, 0.0, 2.5, 1.0];
+///
+/// let pred: Vec<_> = values.iter().zip(&seeds).map(|(&v, &s)| DualNumber::new(v, s)).collect();
+/// let loss = mse(&pred, &target).unwrap();
+///
+/// let closed: f64 = (0..4).map(|i| (values[i] - target[i]) * seeds[i]).sum::<f64>() * 2.0 / 4.0;
+/// assert!((loss.dual() - closed).abs() < 1e-15);
+///
+/// // Finite difference along the seeds
+/// let h = 1e-6;
+/// let plain = |t: f64| (0..4).map(|i| (values[i] + t * seeds[i] - target[i]).powi(2)).sum::<f64>() / 4.0;
+/// assert!((loss.real() - plain(0.0)).abs() < 1e-15);
+/// assert!((loss.dual() - (plain(h) - plain(-h)) / (2.0 * h)).abs() < 1e-9);
+///
+/// assert_eq!(mse(&pred, &target[..3]).unwrap_err(), DualError::LengthMismatch { expected: 4, found: 3 });
+/// ```
+pub fn mse<T>(pred: &[DualNumber<T>], target: &[T]) -> Result<DualNumber<T>, DualError>
+    where T: Float + Signed + FloatConst {
+    mse_sum(pred, target).map(|sum| sum / T::from(pred.len()).unwrap())
+}
+
+/// Sum of absolute errors, `Σ |predᵢ - targetᵢ|`, accumulated with compensated summation.
+///
+/// The kink at a zero residual is handled like `abs`: the derivative follows the sign of the zero,
+/// so a residual of `+0` contributes `predᵢ'` and a residual of `-0` contributes `-predᵢ'`.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, mae_sum};
+/// let pred = [DualNumber::new(1.0f64, 1.0), DualNumber::new(2.5, 3.0)];
+/// assert_eq!(mae_sum(&pred, &[0.5, 3.0]).unwrap().into_tuple(), (1.0, -2.0));
+///
+/// // Residuals of exactly zero
+/// assert_eq!(mae_sum(&[DualNumber::new(2.0f64, 1.0)], &[2.0]).unwrap().into_tuple(), (0.0, 1.0));
+/// assert_eq!(mae_sum(&[DualNumber::new(-0.0f64, 1.0)], &[0.0]).unwrap().into_tuple(), (0.0, -1.0));
+///
+/// // Compensated accumulation of a million terms
+/// let pred = vec![DualNumber::new(0.1f64, 1.0); 1_000_000];
+/// let target = vec![0.0f64; 1_000_000];
+/// let naive: f64 = pred.iter().map(|p| p.real()).sum();
+///
+/// assert_eq!(mae_sum(&pred, &target).unwrap().into_tuple(), (100_000.0, 1_000_000.0));
+/// assert!(naive != 100_000.0);
+/// ```
+pub fn mae_sum<T>(pred: &[DualNumber<T>], target: &[T]) -> Result<DualNumber<T>, DualError>
+    where T: Float + Signed + FloatConst {
+    check_lengths(pred, target)?;
+
+    Ok(compensated_sum(pred.iter().zip(target).map(|(&p, &t)| Signed::abs(&(p - t)))))
+}
+
+/// Mean absolute error, `(1/n) Σ |predᵢ - targetᵢ|`, accumulated with compensated summation.
+///
+/// See `mae_sum` for the derivative at zero residuals. Empty slices give a NaN mean.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber, mae};
+/// let pred = [DualNumber::new(1.0f64, 1.0), DualNumber::new(2.5, 3.0)];
+///
+/// assert_eq!(mae(&pred, &[0.5, 3.0]).unwrap().into_tuple(), (0.5, -1.0));
+/// assert_eq!(mae(&pred, &[]).unwrap_err(), DualError::LengthMismatch { expected: 2, found: 0 });
+/// assert_eq!(mae(&vec![DualNumber::new(0.1f64, 1.0); 1_000_000], &vec![0.0; 1_000_000]).unwrap().real(), 0.1);
+/// ```
+pub fn mae<T>(pred: &[DualNumber<T>], target: &[T]) -> Result<DualNumber<T>, DualError>
+    where T: Float + Signed + FloatConst {
+    mae_sum(pred, target).map(|sum| sum / T::from(pred.len()).unwrap())
+}
+
+/// Huber loss of a residual, `r²/2` for `|r| ≤ δ` and `δ (|r| - δ/2)` outside, for a positive `δ`.
+///
+/// The derivative is `r` inside and `δ sign(r)` outside, so the loss is linear in large residuals.
+/// Both the value and the dual part agree exactly on either side of `|r| = δ`. `δ` may itself be a
+/// dual number, and outside the quadratic region its dual part contributes `δ' (|r| - δ)`.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, huber};
+/// // Quadratic and linear regimes
+/// assert_eq!(huber(DualNumber::new(0.5f64, 1.0), 1.0).into_tuple(), (0.125, 0.5));
+/// assert_eq!(huber(DualNumber::new(-3.0f64, 1.0), 1.0).into_tuple(), (2.5, -1.0));
+/// assert_eq!(huber(DualNumber::new(1e300f64, 1.0), 2.0).into_tuple(), (2e300, 2.0));
+///
+/// // Continuity across ±δ
+/// for &delta in &[1.0f64, 0.1, 1.3, 7e5] {
+///     for &r in &[delta, -delta] {
+///         let at = huber(DualNumber::new(r, 1.0), delta);
+///         let (below, above) = (r.abs() - r.abs() * f64::EPSILON, r.abs() + r.abs() * f64::EPSILON);
+///         assert_eq!(at.into_tuple(), (delta * delta / 2.0, r));
+///
+///         for &s in &[below, above] {
+///             let near = huber(DualNumber::new(s.copysign(r), 1.0), delta);
+///             assert!((near.real() - at.real()).abs() <= 4.0 * f64::EPSILON * at.real());
+///             assert!((near.dual() - at.dual()).abs() <= 4.0 * f64::EPSILON * at.dual().abs());
+///         }
+///     }
+/// }
+///
+/// // Tuning δ itself
+/// let r = DualNumber::from_real(3.0f64);
+/// assert_eq!(huber(r, DualNumber::new(1.0, 1.0)).into_tuple(), (2.5, 2.0));
+/// assert_eq!(huber(r, DualNumber::new(4.0, 1.0)).into_tuple(), (4.5, 0.0));
+/// assert_eq!(huber(r, DualNumber::new(3.0, 1.0)).into_tuple(), (4.5, 0.0));
+///
+/// // Finite differences in r and δ
+/// let h = 1e-6;
+/// let plain = |r: f64, d: f64| if r.abs() <= d { r * r / 2.0 } else { d * (r.abs() - d / 2.0) };
+/// for &(r, d) in &[(0.3f64, 1.0f64), (-2.0, 0.5), (4.0, 2.5)] {
+///     let dr = huber(DualNumber::new(r, 1.0), d).dual();
+///     let dd = huber(DualNumber::from_real(r), DualNumber::new(d, 1.0)).dual();
+///     assert!((dr - (plain(r + h, d) - plain(r - h, d)) / (2.0 * h)).abs() < 1e-8);
+///     assert!((dd - (plain(r, d + h) - plain(r, d - h)) / (2.0 * h)).abs() < 1e-8);
+/// }
+/// ```
+pub fn huber<T, D>(r: DualNumber<T>, delta: D) -> DualNumber<T>
+    where T: Float + Signed + FloatConst, D: Into<DualNumber<T>> {
+    let delta = delta.into();
+    let half = T::from(0.5).unwrap();
+    let a = r.abs();
+
+    if a.real() <= delta.real() {
+        DualNumber::new(r.real() * r.real() * half, r.real() * r.dual())
+    } else {
+        DualNumber::new(
+            delta.real() * (a.real() - delta.real() * half),
+            delta.real() * a.dual() + delta.dual() * (a.real() - delta.real())
+        )
+    }
+}
+
+/// Mean Huber loss over a slice of residuals, accumulated with compensated summation.
+///
+/// Empty slices give a NaN mean.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, huber, huber_mean};
+/// let r = [DualNumber::new(0.5f64, 1.0), DualNumber::new(-3.0, 1.0), DualNumber::new(1.0, 0.0)];
+///
+/// assert_eq!(huber_mean(&r, 1.0).into_tuple(), ((0.125 + 2.5 + 0.5) / 3.0, -0.5 / 3.0));
+/// assert_eq!(huber_mean(&r, DualNumber::new(1.0, 1.0)).dual(), 0.5);
+/// assert!(huber_mean::<f64, f64>(&[], 1.0).real().is_nan());
+/// ```
+pub fn huber_mean<T, D>(r: &[DualNumber<T>], delta: D) -> DualNumber<T>
+    where T: Float + Signed + FloatConst, D: Into<DualNumber<T>> {
+    let delta = delta.into();
+
+    compensated_sum(r.iter().map(|&r| huber(r, delta))) / T::from(r.len()).unwrap()
+}
+
+/// Pseudo-Huber loss, `δ² (√(1 + (r/δ)²) - 1)`, a smooth approximation of `huber`.
+///
+/// It is evaluated as `r² / (1 + √(1 + (r/δ)²))`, which avoids cancellation for small residuals
+/// and overflow for large ones. `δ` may itself be a dual number.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, pseudo_huber};
+/// let closed = |r: f64, d: f64| d * d * ((1.0 + (r / d).powi(2)).sqrt() - 1.0);
+/// let slope = |r: f64, d: f64| r / (1.0 + (r / d).powi(2)).sqrt();
+///
+/// for &(r, d) in &[(0.5f64, 1.0f64), (-3.0, 1.0), (2.0, 0.25), (1e-3, 2.0)] {
+///     let y = pseudo_huber(DualNumber::new(r, 1.0), d);
+///     assert!((y.real() - closed(r, d)).abs() < 1e-14 * closed(r, d).max(1.0));
+///     assert!((y.dual() - slope(r, d)).abs() < 1e-14);
+/// }
+///
+/// // Quadratic for small residuals, linear for large ones, without cancellation or overflow
+/// let near = pseudo_huber(DualNumber::new(1e-10f64, 1.0), 1.0);
+/// assert!((near.real() / 5e-21 - 1.0).abs() < 1e-15 && near.dual() == 1e-10);
+/// let far = pseudo_huber(DualNumber::new(1e300f64, 1.0), 2.0);
+/// assert!((far.real() / 2e300 - 1.0).abs() < 1e-15 && (far.dual() - 2.0).abs() < 1e-15);
+///
+/// // Finite difference in δ
+/// let h = 1e-6;
+/// let dd = pseudo_huber(DualNumber::from_real(1.5f64), DualNumber::new(0.75, 1.0)).dual();
+/// assert!((dd - (closed(1.5, 0.75 + h) - closed(1.5, 0.75 - h)) / (2.0 * h)).abs() < 1e-8);
+/// ```
+pub fn pseudo_huber<T, D>(r: DualNumber<T>, delta: D) -> DualNumber<T>
+    where T: Float + Signed + FloatConst, D: Into<DualNumber<T>> {
+    let one = DualNumber::from_real(T::one());
+
+    r * (r / (one + one.hypot(r / delta.into())))
+}
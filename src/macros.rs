@@ -0,0 +1,135 @@
+//! Exported macros
+
+/// Asserts that both parts of a dual number are within a tolerance of the expected values.
+///
+/// Takes `(actual, real, dual, tolerance)`, and the panic message says which part failed.
+/// NaN parts always fail.
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate dual_num;
+///
+/// use dual_num::{DualNumber, Float};
+///
+/// # fn main() {
+/// let x = DualNumber::new(4.0f64, 1.0).sqrt();
+///
+/// assert_dual_eq!(x, 2.0, 0.25, 1e-15);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// #[macro_use]
+/// extern crate dual_num;
+///
+/// use dual_num::{DualNumber, Float};
+///
+/// # fn main() {
+/// // Panics with "assertion failed: dual parts differ"
+/// assert_dual_eq!(DualNumber::new(4.0f64, 1.0).sqrt(), 2.0, 0.5, 1e-15);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_dual_eq {
+    ($actual:expr, $real:expr, $dual:expr, $tol:expr) => {{
+        let actual = $actual;
+        let (real, dual, tol) = ($real, $dual, $tol);
+
+        let (actual_real, actual_dual) = (actual.real(), actual.dual());
+
+        if !($crate::Float::abs(actual_real - real) <= tol) {
+            panic!("assertion failed: real parts differ\n    actual: {}\n  expected: {}\n tolerance: {}",
+                   actual_real, real, tol);
+        }
+
+        if !($crate::Float::abs(actual_dual - dual) <= tol) {
+            panic!("assertion failed: dual parts differ\n    actual: {}\n  expected: {}\n tolerance: {}",
+                   actual_dual, dual, tol);
+        }
+    }};
+}
+
+/// Constructs a dual number, expanding to `DualNumber::new`.
+///
+/// Three forms are accepted:
+///
+/// * `dual!(real)`, with a dual part of `0.0`, so only for float parts
+/// * `dual!(real, dual)`, where both parts are expressions
+/// * `dual!(real + dual ε)` or `dual!(real - dual ε)`, also spelled with `eps`, where both parts
+///   are numeric literals, such as `dual!(3.0 + 1.0 ε)` or `dual!(-2.5 - 0.5 eps)`
+///
+/// Since `DualNumber::new` is a `const fn`, the macro works in constants and statics as well.
+///
+/// A crate whose only Greek identifier is `ε` gets a `mixed_script_confusables` warning for it, which
+/// `#![allow(mixed_script_confusables)]` silences, or use `eps` instead.
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate dual_num;
+///
+/// use dual_num::DualNumber;
+///
+/// const SEED: DualNumber<f64> = dual!(3.0 + 1.0 ε);
+/// static TABLE: [DualNumber<f64>; 4] = [dual!(1.0), dual!(2.0, -1.0), dual!(0.5 - 2.0 eps), dual!(-4.0 + -1.5 ε)];
+///
+/// # fn main() {
+/// assert_eq!(SEED.into_tuple(), (3.0, 1.0));
+/// assert_eq!(dual!(2.5).into_tuple(), (2.5, 0.0));
+/// assert_eq!(dual!(2.5f32).into_tuple(), (2.5, 0.0));
+///
+/// let x = 1.5f64;
+/// assert_eq!(dual!(x * 2.0, -x).into_tuple(), (3.0, -1.5));
+/// assert_eq!(dual!(3, -1).into_tuple(), (3, -1));
+///
+/// let parts: Vec<_> = TABLE.iter().map(|x| x.into_tuple()).collect();
+/// assert_eq!(parts, vec![(1.0, 0.0), (2.0, -1.0), (0.5, -2.0), (-4.0, -1.5)]);
+///
+/// let class = |x: DualNumber<f64>| match x {
+///     x if x == dual!(0.0) => "zero",
+///     x if x.dual() < dual!(1.0 + -1.0 eps).dual() => "decreasing fast",
+///     _ => "other",
+/// };
+/// assert_eq!(class(dual!(0.0 - 5.0 ε)), "zero");
+/// assert_eq!(class(dual!(1.0 - 2.0 ε)), "decreasing fast");
+/// assert_eq!(class(dual!(1.0, 0.0)), "other");
+/// # }
+/// ```
+///
+/// Note that without a trailing `ε`, `dual!(3.0 + 1.0)` is the real number `4.0`. Anything else fails
+/// to compile:
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate dual_num;
+///
+/// # fn main() {
+/// let x = dual!(3.0 + 1.0 e);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate dual_num;
+///
+/// # fn main() {
+/// let x = dual!(3.0, 1.0, 2.0);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate dual_num;
+///
+/// # fn main() {
+/// let x = dual!(3.0 * 1.0 ε);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! dual {
+    ($real:literal + $dual:literal ε) => { $crate::DualNumber::new($real, $dual) };
+    ($real:literal + $dual:literal eps) => { $crate::DualNumber::new($real, $dual) };
+    ($real:literal - $dual:literal ε) => { $crate::DualNumber::new($real, -$dual) };
+    ($real:literal - $dual:literal eps) => { $crate::DualNumber::new($real, -$dual) };
+    ($real:expr, $dual:expr) => { $crate::DualNumber::new($real, $dual) };
+    ($real:expr) => { $crate::DualNumber::new($real, 0.0) };
+}
@@ -0,0 +1,119 @@
+//! Normalization layers
+//!
+//! The mean and variance are computed on dual numbers, so the dual parts of the outputs include
+//! the derivatives of both statistics with respect to every seeded input.
+
+use super::{DualError, DualNumber, Float, FloatConst, Zero};
+
+use num_traits::Signed;
+
+/// Mean and population variance of `xs` in a single pass, with Welford's update.
+fn moments<T>(xs: &[DualNumber<T>]) -> (DualNumber<T>, DualNumber<T>) where T: Float + Signed + FloatConst {
+    let mut mean = DualNumber::zero();
+    let mut m2 = DualNumber::zero();
+
+    for (k, &x) in xs.iter().enumerate() {
+        let delta = x - mean;
+
+        mean = mean + delta / T::from(k + 1).unwrap();
+        m2 = m2 + delta * (x - mean);
+    }
+
+    (mean, m2 / T::from(xs.len()).unwrap())
+}
+
+/// Normalizes `xs` to zero mean and unit variance, `(xᵢ - μ) / √(σ² + eps)`.
+///
+/// `μ` and `σ²` are the mean and population variance of the slice, found in one pass. The dual parts
+/// are the Jacobian-vector product of the whole operation with the dual parts of the inputs,
+/// `(xᵢ' - μ') s - (xᵢ - μ) s³ (Σ (xⱼ - μ) xⱼ') / n` with `s = 1/√(σ² + eps)`.
+///
+/// A constant input normalizes to zeros, whose dual parts stay finite as long as `eps` is positive.
+/// An empty slice gives an empty result.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, layer_norm};
+/// let x = [1.0f64, 4.0, -2.0, 0.5, 3.25];
+/// let seeds = [0.3f64, -1.0, 0.7, 2.0, -0.4];
+/// let xs: Vec<_> = x.iter().zip(&seeds).map(|(&x, &s)| DualNumber::new(x, s)).collect();
+/// let y = layer_norm(&xs, 0.0);
+///
+/// let mean = y.iter().map(|y| y.real()).sum::<f64>() / 5.0;
+/// let var = y.iter().map(|y| y.real() * y.real()).sum::<f64>() / 5.0;
+/// assert!(mean.abs() < 1e-15 && (var - 1.0).abs() < 1e-15);
+///
+/// // Finite difference along the seed direction
+/// let plain = |t: f64, eps: f64| {
+///     let v: Vec<f64> = x.iter().zip(&seeds).map(|(x, s)| x + t * s).collect();
+///     let mean = v.iter().sum::<f64>() / 5.0;
+///     let var = v.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / 5.0;
+///     v.iter().map(|v| (v - mean) / (var + eps).sqrt()).collect::<Vec<_>>()
+/// };
+/// let h = 1e-6;
+/// for &eps in &[0.0, 0.1] {
+///     let y = layer_norm(&xs, eps);
+///     let (up, down) = (plain(h, eps), plain(-h, eps));
+///     for i in 0..5 {
+///         assert!((y[i].real() - plain(0.0, eps)[i]).abs() < 1e-15);
+///         assert!((y[i].dual() - (up[i] - down[i]) / (2.0 * h)).abs() < 1e-8);
+///     }
+/// }
+///
+/// // Constant inputs only keep the deviations of their dual parts
+/// let flat: Vec<_> = seeds.iter().map(|&s| DualNumber::new(2.0f64, s)).collect();
+/// let mean_seed = seeds.iter().sum::<f64>() / 5.0;
+/// for (y, s) in layer_norm(&flat, 0.25).iter().zip(&seeds) {
+///     assert_eq!(y.real(), 0.0);
+///     assert!((y.dual() - (s - mean_seed) / 0.5).abs() < 1e-15);
+/// }
+///
+/// assert_eq!(layer_norm(&[DualNumber::new(3.0f64, 1.0)], 1e-5)[0].into_tuple(), (0.0, 0.0));
+/// assert!(layer_norm::<f64>(&[], 1e-5).is_empty());
+/// ```
+pub fn layer_norm<T>(xs: &[DualNumber<T>], eps: T) -> Vec<DualNumber<T>> where T: Float + Signed + FloatConst {
+    if xs.is_empty() {
+        return Vec::new();
+    }
+
+    let (mean, var) = moments(xs);
+    let scale = (var + eps).sqrt().recip();
+
+    xs.iter().map(|&x| (x - mean) * scale).collect()
+}
+
+/// Layer normalization followed by an element-wise affine map, `gainᵢ layer_norm(xs)ᵢ + biasᵢ`.
+///
+/// The gain and bias are dual numbers, so they can be seeded to differentiate with respect to them.
+///
+/// Returns `DualError::LengthMismatch` if `gain` or `bias` has a different length than `xs`.
+///
+/// ```rust
+/// # use dual_num::{DualError, DualNumber, layer_norm, layer_norm_affine};
+/// let xs = [DualNumber::new(1.0f64, 1.0), DualNumber::new(2.0, 0.0), DualNumber::new(6.0, 0.0)];
+/// let gain = [DualNumber::new(2.0f64, 0.0), DualNumber::new(0.5, 1.0), DualNumber::from_real(1.0)];
+/// let bias = [DualNumber::from_real(1.0f64), DualNumber::from_real(-1.0), DualNumber::new(0.0, 1.0)];
+///
+/// let n = layer_norm(&xs, 1e-5);
+/// let y = layer_norm_affine(&xs, &gain, &bias, 1e-5).unwrap();
+///
+/// for i in 0..3 {
+///     assert_eq!(y[i], gain[i] * n[i] + bias[i]);
+/// }
+/// // Seeding a gain or bias differentiates with respect to it
+/// assert_eq!(y[2].dual(), 1.0 + n[2].dual());
+///
+/// assert_eq!(layer_norm_affine(&xs, &gain[..2], &bias, 1e-5).unwrap_err(),
+///            DualError::LengthMismatch { expected: 3, found: 2 });
+/// assert_eq!(layer_norm_affine(&xs, &gain, &[], 1e-5).unwrap_err(),
+///            DualError::LengthMismatch { expected: 3, found: 0 });
+/// ```
+pub fn layer_norm_affine<T>(xs: &[DualNumber<T>], gain: &[DualNumber<T>], bias: &[DualNumber<T>], eps: T)
+    -> Result<Vec<DualNumber<T>>, DualError> where T: Float + Signed + FloatConst {
+    for params in &[gain, bias] {
+        if params.len() != xs.len() {
+            return Err(DualError::LengthMismatch { expected: xs.len(), found: params.len() });
+        }
+    }
+
+    Ok(layer_norm(xs, eps).into_iter().zip(gain).zip(bias).map(|((y, &g), &b)| g * y + b).collect())
+}
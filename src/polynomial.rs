@@ -0,0 +1,582 @@
+//! Polynomial evaluation at dual points
+//!
+//! The recurrences here operate directly on dual numbers, so derivatives with
+//! respect to the evaluation point propagate through them automatically.
+
+use super::{DualNumber, Float, FloatConst, Num, One, Zero};
+
+use num_traits::Signed;
+
+/// Evaluates a polynomial at a dual point with Horner's method, giving its value and derivative in one pass.
+///
+/// The coefficients are ordered from the highest degree to the constant term, so `[1, -3, 2]` is `x² - 3x + 2`.
+/// An empty slice is the zero polynomial.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, horner};
+/// let p = horner(&[1.0, -3.0, 2.0], DualNumber::new(4.0f64, 1.0));
+///
+/// assert_eq!(p.into_tuple(), (6.0, 5.0));
+/// ```
+pub fn horner<T: Num + Copy>(coeffs: &[T], x: DualNumber<T>) -> DualNumber<T> {
+    coeffs.iter().fold(DualNumber::zero(), |acc, &c| acc * x + c)
+}
+
+/// Legendre polynomial `Pₙ(x)`, evaluated with Bonnet's recurrence
+/// `(n + 1) Pₙ₊₁ = (2n + 1) x Pₙ - n Pₙ₋₁`.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, legendre_p};
+/// let x = DualNumber::new(0.3f64, 1.0);
+///
+/// let closed: [(fn(f64) -> f64, fn(f64) -> f64); 6] = [
+///     (|_| 1.0, |_| 0.0),
+///     (|x| x, |_| 1.0),
+///     (|x| (3.0 * x * x - 1.0) / 2.0, |x| 3.0 * x),
+///     (|x| (5.0 * x.powi(3) - 3.0 * x) / 2.0, |x| (15.0 * x * x - 3.0) / 2.0),
+///     (|x| (35.0 * x.powi(4) - 30.0 * x * x + 3.0) / 8.0, |x| (140.0 * x.powi(3) - 60.0 * x) / 8.0),
+///     (|x| (63.0 * x.powi(5) - 70.0 * x.powi(3) + 15.0 * x) / 8.0,
+///      |x| (315.0 * x.powi(4) - 210.0 * x * x + 15.0) / 8.0),
+/// ];
+///
+/// for (n, &(p, dp)) in closed.iter().enumerate() {
+///     let v = legendre_p(n as u32, x);
+///     assert!((v.real() - p(0.3)).abs() < 1e-15);
+///     assert!((v.dual() - dp(0.3)).abs() < 1e-14);
+/// }
+///
+/// // (1 - x²) Pₙ' = n (Pₙ₋₁ - x Pₙ), and endpoint values, up to n = 50
+/// for &n in &[5u32, 50] {
+///     let (p, q) = (legendre_p(n, x), legendre_p(n - 1, x));
+///     assert!(((1.0 - 0.09) * p.dual() - n as f64 * (q.real() - 0.3 * p.real())).abs() < 1e-12);
+///     assert!(p.real().abs() <= 1.0);
+///
+///     let one = legendre_p(n, DualNumber::new(1.0f64, 1.0));
+///     assert!((one.real() - 1.0).abs() < 1e-14);
+///     assert!((one.dual() - (n * (n + 1)) as f64 / 2.0).abs() < 1e-10);
+///     assert!((legendre_p(n, DualNumber::new(-1.0f64, 1.0)).real() - (-1f64).powi(n as i32)).abs() < 1e-14);
+/// }
+/// ```
+pub fn legendre_p<T>(n: u32, x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let mut prev = DualNumber::one();
+
+    if n == 0 {
+        return prev;
+    }
+
+    let mut curr = x;
+
+    for k in 1..n {
+        let k = T::from(k).unwrap();
+        let next = (x * curr * (k + k + T::one()) - prev * k) / (k + T::one());
+
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+/// Associated Legendre function `Pₗᵐ(x)` for `|x| <= 1`, without the Condon-Shortley phase.
+///
+/// This is the convention used in geodesy, and by C++'s `std::assoc_legendre`. Multiply by `(-1)ᵐ`
+/// to include the phase. Returns zero for `m > l`.
+///
+/// The recurrence starts from `Pₘᵐ = (2m - 1)!! (1 - x²)^(m/2)`, whose derivative is computed in
+/// closed form rather than through `sqrt(1 - x²)`, so that it stays finite at the poles `x = ±1`.
+/// The only exception is `m = 1`, where `(1 - x²)^(1/2)` has a vertical tangent and the derivative
+/// is infinite.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, legendre_p, legendre_p_assoc};
+/// let x = DualNumber::new(0.3f64, 1.0);
+/// let s = (1.0f64 - 0.09).sqrt();
+///
+/// assert!((legendre_p_assoc(1, 1, x).real() - s).abs() < 1e-15);
+/// assert!((legendre_p_assoc(1, 1, x).dual() + 0.3 / s).abs() < 1e-15);
+/// assert!((legendre_p_assoc(2, 1, x).real() - 3.0 * 0.3 * s).abs() < 1e-15);
+/// assert!((legendre_p_assoc(2, 2, x).real() - 3.0 * (1.0 - 0.09)).abs() < 1e-15);
+/// assert!((legendre_p_assoc(2, 2, x).dual() + 6.0 * 0.3).abs() < 1e-15);
+///
+/// assert_eq!(legendre_p_assoc(4, 0, x).into_tuple(), legendre_p(4, x).into_tuple());
+/// assert_eq!(legendre_p_assoc(2, 3, x).into_tuple(), (0.0, 0.0));
+///
+/// // At the poles, for even and odd m
+/// let (north, south) = (DualNumber::new(1.0f64, 1.0), DualNumber::new(-1.0f64, 1.0));
+/// assert_eq!(legendre_p_assoc(2, 2, north).into_tuple(), (0.0, -6.0));
+/// assert_eq!(legendre_p_assoc(2, 2, south).into_tuple(), (0.0, 6.0));
+/// assert_eq!(legendre_p_assoc(3, 2, north).into_tuple(), (0.0, -30.0));
+/// assert_eq!(legendre_p_assoc(3, 2, south).into_tuple(), (0.0, -30.0));
+/// assert_eq!(legendre_p_assoc(4, 4, north).into_tuple(), (0.0, 0.0));
+/// assert_eq!(legendre_p_assoc(3, 3, north).into_tuple(), (0.0, 0.0));
+/// assert_eq!(legendre_p_assoc(3, 3, south).into_tuple(), (0.0, 0.0));
+/// assert_eq!(legendre_p_assoc(5, 3, south).into_tuple(), (0.0, 0.0));
+/// assert_eq!(legendre_p_assoc(2, 0, north).into_tuple(), (1.0, 3.0));
+/// assert_eq!(legendre_p_assoc(1, 1, north).into_tuple(), (0.0, f64::NEG_INFINITY));
+/// assert_eq!(legendre_p_assoc(1, 1, south).into_tuple(), (0.0, f64::INFINITY));
+///
+/// // Close to the poles, the derivatives approach those at the poles
+/// let near = legendre_p_assoc(3, 3, DualNumber::new(1.0f64 - 1e-12, 1.0));
+/// assert!(near.dual().abs() < 1e-4);
+/// let near = legendre_p_assoc(3, 2, DualNumber::new(-1.0f64 + 1e-12, 1.0));
+/// assert!((near.dual() + 30.0).abs() < 1e-9);
+/// ```
+pub fn legendre_p_assoc<T>(l: u32, m: u32, x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    if m > l {
+        return DualNumber::from_real(T::zero());
+    }
+
+    // Pₘᵐ = (2m - 1)!! (1 - x²)^(m/2), with derivative -(2m - 1)!! m x (1 - x²)^(m/2 - 1) x'
+    let mut pmm = DualNumber::one();
+
+    if m > 0 {
+        let mut c = T::one();
+
+        for k in 0..m {
+            c = c * T::from(2 * k + 1).unwrap();
+        }
+
+        let w = T::one() - x.real() * x.real();
+        let half = (m / 2) as i32;
+
+        let (power, slope) = if m & 1 == 0 {
+            (w.powi(half), w.powi(half - 1))
+        } else if m == 1 {
+            (w.sqrt(), w.sqrt().recip())
+        } else {
+            (w.powi(half) * w.sqrt(), w.powi(half - 1) * w.sqrt())
+        };
+
+        pmm = DualNumber::new(c * power, -(c * T::from(m).unwrap() * x.real() * slope * x.dual()));
+    }
+
+    if l == m {
+        return pmm;
+    }
+
+    // Pₘ₊₁ᵐ = x (2m + 1) Pₘᵐ
+    let mut prev = pmm;
+    let mut curr = x * pmm * T::from(2 * m + 1).unwrap();
+
+    // (l - m) Pₗᵐ = x (2l - 1) Pₗ₋₁ᵐ - (l + m - 1) Pₗ₋₂ᵐ
+    for k in (m + 2)..(l + 1) {
+        let next = (x * curr * T::from(2 * k - 1).unwrap() - prev * T::from(k + m - 1).unwrap())
+            / T::from(k - m).unwrap();
+
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+/// Fully normalized associated Legendre function `P̄ₗᵐ(x)`, as used for spherical-harmonic gravity models.
+///
+/// This is `Pₗᵐ(x) sqrt((2 - δₘ₀)(2l + 1)(l - m)! / (l + m)!)`, with `Pₗᵐ` from `legendre_p_assoc`. It is
+/// evaluated with the normalized recurrences rather than by scaling `Pₗᵐ`, which overflows for large
+/// orders: `P̄ₘᵐ = sqrt((2m + 1)/(2m)) sqrt(1 - x²) P̄ₘ₋₁ᵐ⁻¹` along the diagonal, then
+/// `P̄ₗᵐ = aₗₘ x P̄ₗ₋₁ᵐ - bₗₘ P̄ₗ₋₂ᵐ` in the degree.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, legendre_p, legendre_p_assoc, legendre_p_assoc_normalized};
+/// let x = DualNumber::new(0.3f64, 1.0);
+///
+/// let p = legendre_p_assoc_normalized(3, 0, x);
+/// assert!((p.real() - 7f64.sqrt() * legendre_p(3, x).real()).abs() < 1e-15);
+/// assert!((p.dual() - 7f64.sqrt() * legendre_p(3, x).dual()).abs() < 1e-14);
+///
+/// let p = legendre_p_assoc_normalized(2, 1, x);
+/// assert!((p.real() - (10.0f64 / 6.0).sqrt() * legendre_p_assoc(2, 1, x).real()).abs() < 1e-15);
+///
+/// let p = legendre_p_assoc_normalized(5, 3, x);
+/// let scale = (2.0 * 11.0 / 40_320.0f64 * 2.0).sqrt();
+/// assert!((p.real() - scale * legendre_p_assoc(5, 3, x).real()).abs() < 1e-14);
+/// assert!((p.dual() - scale * legendre_p_assoc(5, 3, x).dual()).abs() < 1e-13);
+///
+/// // High orders, where the unnormalized functions overflow
+/// for &(l, m) in &[(200, 200), (200, 150), (360, 180)] {
+///     let p = legendre_p_assoc_normalized(l, m, x);
+///     assert!(p.real().is_finite() && p.dual().is_finite());
+/// }
+/// assert_eq!(legendre_p_assoc_normalized(2, 3, x).into_tuple(), (0.0, 0.0));
+/// ```
+pub fn legendre_p_assoc_normalized<T>(l: u32, m: u32, x: DualNumber<T>) -> DualNumber<T>
+    where T: Float + Signed + FloatConst {
+    if m > l {
+        return DualNumber::from_real(T::zero());
+    }
+
+    let n = |k: u32| T::from(k).unwrap();
+
+    // P̄ₘᵐ = c (1 - x²)^(m/2), with c = sqrt(3) Π sqrt((2k + 1)/(2k)) for k in 2..=m
+    let mut pmm = DualNumber::one();
+
+    if m > 0 {
+        let mut c = n(3).sqrt();
+
+        for k in 2..(m + 1) {
+            c = c * (n(2 * k + 1) / n(2 * k)).sqrt();
+        }
+
+        let w = T::one() - x.real() * x.real();
+        let half = (m / 2) as i32;
+
+        let (power, slope) = if m & 1 == 0 {
+            (w.powi(half), w.powi(half - 1))
+        } else if m == 1 {
+            (w.sqrt(), w.sqrt().recip())
+        } else {
+            (w.powi(half) * w.sqrt(), w.powi(half - 1) * w.sqrt())
+        };
+
+        pmm = DualNumber::new(c * power, -(c * n(m) * x.real() * slope * x.dual()));
+    }
+
+    if l == m {
+        return pmm;
+    }
+
+    // P̄ₘ₊₁ᵐ = sqrt(2m + 3) x P̄ₘᵐ
+    let mut prev = pmm;
+    let mut curr = x * pmm * n(2 * m + 3).sqrt();
+
+    // aₗₘ = sqrt((2l + 1)(2l - 1) / ((l - m)(l + m)))
+    // bₗₘ = sqrt((2l + 1)(l + m - 1)(l - m - 1) / ((l - m)(l + m)(2l - 3)))
+    for k in (m + 2)..(l + 1) {
+        let d = n(k - m) * n(k + m);
+        let a = (n(2 * k + 1) * n(2 * k - 1) / d).sqrt();
+        let b = (n(2 * k + 1) * n(k + m - 1) * n(k - m - 1) / (d * n(2 * k - 3))).sqrt();
+        let next = x * curr * a - prev * b;
+
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+/// Chebyshev polynomial of the first kind `Tₙ(x)`, evaluated with the recurrence `Tₙ₊₁ = 2x Tₙ - Tₙ₋₁`.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, chebyshev_t};
+/// let x = DualNumber::new(0.3f64, 1.0);
+/// let t = |n| chebyshev_t(n, x).into_tuple();
+///
+/// assert_eq!(t(0), (1.0, 0.0));
+/// assert_eq!(t(1), (0.3, 1.0));
+///
+/// let (v, d) = t(2);
+/// assert!((v - (2.0 * 0.09 - 1.0)).abs() < 1e-15 && (d - 4.0 * 0.3).abs() < 1e-15);
+/// let (v, d) = t(3);
+/// assert!((v - (4.0 * 0.027 - 0.9)).abs() < 1e-15 && (d - (12.0 * 0.09 - 3.0)).abs() < 1e-15);
+/// let (v, d) = t(4);
+/// assert!((v - (8.0 * 0.0081 - 8.0 * 0.09 + 1.0)).abs() < 1e-15);
+/// assert!((d - (32.0 * 0.027 - 16.0 * 0.3)).abs() < 1e-15);
+/// ```
+pub fn chebyshev_t<T>(n: u32, x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let mut prev = DualNumber::one();
+
+    if n == 0 {
+        return prev;
+    }
+
+    let mut curr = x;
+
+    for _ in 1..n {
+        let next = x * curr * T::from(2).unwrap() - prev;
+
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+/// Evaluates the Chebyshev series `Σ cₖ Tₖ(u)` with Clenshaw's algorithm, where `u` is `x` mapped
+/// affinely from `domain` onto `[-1, 1]`.
+///
+/// All coefficients, including `c₀`, are used with full weight. Since the mapping is done on the
+/// dual number, its constant Jacobian `2 / (b - a)` is included in the derivative.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, chebyshev_eval, chebyshev_t};
+/// let coeffs = [1.0f64, 2.0, 3.0, -0.5];
+/// let x = DualNumber::new(0.3f64, 1.0);
+///
+/// // Matches direct summation on [-1, 1]
+/// let direct = (0..4).fold(DualNumber::from_real(0.0), |acc, k| acc + chebyshev_t(k, x) * coeffs[k as usize]);
+/// let f = chebyshev_eval(&coeffs, x, (-1.0, 1.0));
+/// assert!((f.real() - direct.real()).abs() < 1e-15);
+/// assert!((f.dual() - direct.dual()).abs() < 1e-14);
+///
+/// // On [0, 4], u = x/2 - 1, so 1 + 2T₁ + 3T₂ has derivative (2 + 12u) / 2
+/// let f = chebyshev_eval(&[1.0, 2.0, 3.0], DualNumber::new(3.0f64, 1.0), (0.0, 4.0));
+/// assert_eq!(f.into_tuple(), (0.5, 4.0));
+///
+/// // The domain endpoints map to Tₖ(±1) = (±1)ᵏ
+/// assert_eq!(chebyshev_eval(&coeffs, DualNumber::from_real(4.0), (0.0, 4.0)).real(), 5.5);
+/// assert_eq!(chebyshev_eval(&coeffs, DualNumber::from_real(0.0), (0.0, 4.0)).real(), 2.5);
+/// ```
+pub fn chebyshev_eval<T>(coeffs: &[T], x: DualNumber<T>, domain: (T, T)) -> DualNumber<T>
+    where T: Float + Signed + FloatConst {
+    let (a, b) = domain;
+    let u = (x * T::from(2).unwrap() - (a + b)) / (b - a);
+
+    let (c0, rest) = match coeffs.split_first() {
+        Some(split) => split,
+        None => return DualNumber::zero(),
+    };
+
+    // bₖ = cₖ + 2u bₖ₊₁ - bₖ₊₂, for k from n down to 1
+    let mut b1 = DualNumber::zero();
+    let mut b2 = DualNumber::zero();
+
+    for &c in rest.iter().rev() {
+        let b0 = u * b1 * T::from(2).unwrap() - b2 + c;
+
+        b2 = b1;
+        b1 = b0;
+    }
+
+    u * b1 - b2 + *c0
+}
+
+/// Physicists' Hermite polynomial `Hₙ(x)`, evaluated with the recurrence `Hₙ₊₁ = 2x Hₙ - 2n Hₙ₋₁`.
+///
+/// `Hₙ(x)` grows roughly like `√(2ⁿ n!)` and overflows for large `n` or `|x|` (past `n ≈ 150`
+/// for `f64` near the origin, and much sooner for `f32`); `hermite_h_scaled` stays representable.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, hermite_h};
+/// let x = DualNumber::new(0.5f64, 1.0);
+/// let h = |n| hermite_h(n, x).into_tuple();
+///
+/// assert_eq!(h(0), (1.0, 0.0));
+/// assert_eq!(h(1), (1.0, 2.0));
+/// assert_eq!(h(2), (4.0 * 0.25 - 2.0, 8.0 * 0.5));
+/// assert_eq!(h(3), (8.0 * 0.125 - 12.0 * 0.5, 24.0 * 0.25 - 12.0));
+/// assert_eq!(h(4), (16.0 * 0.0625 - 48.0 * 0.25 + 12.0, 64.0 * 0.125 - 96.0 * 0.5));
+///
+/// // Hₙ' = 2n Hₙ₋₁, and Hₙ(-x) = (-1)ⁿ Hₙ(x)
+/// for n in 1..12 {
+///     let hn = hermite_h(n, x);
+///     assert!((hn.dual() - 2.0 * n as f64 * hermite_h(n - 1, x).real()).abs() < 1e-9 * hn.dual().abs());
+///
+///     let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+///     assert_eq!(hermite_h(n, -x).real(), sign * hn.real());
+/// }
+/// ```
+pub fn hermite_h<T>(n: u32, x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let two = T::from(2).unwrap();
+    let mut prev = DualNumber::one();
+
+    if n == 0 {
+        return prev;
+    }
+
+    let mut curr = x * two;
+
+    for k in 1..n {
+        let next = x * curr * two - prev * (two * T::from(k).unwrap());
+
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+/// Scaled Hermite polynomial `Hₙ(x) / √(2ⁿ n!)`.
+///
+/// The scaling is folded into the recurrence, `hₙ₊₁ = √(2/(n+1)) x hₙ - √(n/(n+1)) hₙ₋₁`,
+/// so no intermediate value overflows for large `n`. Its derivative is `√(2n) hₙ₋₁`.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, hermite_h, hermite_h_scaled};
+/// let x = DualNumber::new(1.5f64, 1.0);
+///
+/// // Agrees with the unscaled polynomial where the latter is representable
+/// let norm = (1..=100).fold(1.0f64, |acc, k| acc * (2.0 * k as f64).sqrt());
+/// let (h, s) = (hermite_h(100, x), hermite_h_scaled(100, x));
+/// assert!((s.real() - h.real() / norm).abs() < 1e-12 * s.real().abs());
+/// assert!((s.dual() - h.dual() / norm).abs() < 1e-12 * s.dual().abs());
+/// assert!((s.dual() - 200f64.sqrt() * hermite_h_scaled(99, x).real()).abs() < 1e-12 * s.dual().abs());
+///
+/// // In single precision, H₁₀₀ overflows but the scaled variant does not
+/// let x = DualNumber::new(1.5f32, 1.0);
+/// assert!(!hermite_h(100, x).real().is_finite());
+/// assert!(hermite_h_scaled(100, x).real().is_finite());
+/// assert!(hermite_h_scaled(100, x).dual().is_finite());
+/// ```
+pub fn hermite_h_scaled<T>(n: u32, x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let two = T::from(2).unwrap();
+    let mut prev = DualNumber::one();
+
+    if n == 0 {
+        return prev;
+    }
+
+    let mut curr = x * two.sqrt();
+
+    for k in 1..n {
+        let k = T::from(k).unwrap();
+        let next = x * curr * (two / (k + T::one())).sqrt() - prev * (k / (k + T::one())).sqrt();
+
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+/// Laguerre polynomial `Lₙ(x)`, the generalized Laguerre polynomial with `α = 0`.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, laguerre_l};
+/// let x = DualNumber::new(1.5f64, 1.0);
+/// let l = |n| laguerre_l(n, x).into_tuple();
+///
+/// assert_eq!(l(0), (1.0, 0.0));
+/// assert_eq!(l(1), (-0.5, -1.0));
+///
+/// let (v, d) = l(2);
+/// assert!((v - (1.5f64 * 1.5 - 6.0 + 2.0) / 2.0).abs() < 1e-15 && (d - (1.5 - 2.0)).abs() < 1e-15);
+/// let (v, d) = l(3);
+/// assert!((v - (-3.375 + 9.0 * 2.25 - 27.0 + 6.0) / 6.0).abs() < 1e-15);
+/// assert!((d - (-3.0 * 2.25 + 27.0 - 18.0) / 6.0).abs() < 1e-15);
+///
+/// // Lₙ(0) = 1, and |Lₙ(x)| <= e^(x/2) for x >= 0
+/// for n in 0..=50 {
+///     assert!((laguerre_l(n, DualNumber::from_real(0.0f64)).real() - 1.0).abs() < 1e-12);
+///     assert!(laguerre_l(n, DualNumber::from_real(20.0f64)).real().abs() <= 10f64.exp());
+/// }
+/// ```
+pub fn laguerre_l<T>(n: u32, x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    laguerre_l_gen(n, T::zero(), x)
+}
+
+/// Generalized Laguerre polynomial `Lₙ⁽ᵅ⁾(x)`, evaluated with the recurrence
+/// `(k + 1) Lₖ₊₁ = (2k + 1 + α - x) Lₖ - (k + α) Lₖ₋₁`.
+///
+/// Its derivative satisfies `d/dx Lₙ⁽ᵅ⁾ = -Lₙ₋₁⁽ᵅ⁺¹⁾`.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, laguerre_l_gen};
+/// let x = DualNumber::new(0.8f64, 1.0);
+/// let alpha = 2.5;
+///
+/// let (v, d) = laguerre_l_gen(1, alpha, x).into_tuple();
+/// assert!((v - (alpha + 1.0 - 0.8)).abs() < 1e-15 && d == -1.0);
+///
+/// let (v, d) = laguerre_l_gen(2, alpha, x).into_tuple();
+/// let reference = 0.32 - (alpha + 2.0) * 0.8 + (alpha + 2.0) * (alpha + 1.0) / 2.0;
+/// assert!((v - reference).abs() < 1e-14 && (d - (0.8 - alpha - 2.0)).abs() < 1e-14);
+///
+/// for n in 1..=50 {
+///     let l = laguerre_l_gen(n, alpha, x);
+///     let expected = -laguerre_l_gen(n - 1, alpha + 1.0, x).real();
+///     assert!((l.dual() - expected).abs() < 1e-10 * expected.abs().max(1.0));
+/// }
+///
+/// // Lₙ⁽ᵅ⁾(0) is the binomial coefficient (n + α choose n)
+/// let binomial = (1..=50).fold(1.0f64, |acc, k| acc * (k as f64 + alpha) / k as f64);
+/// let l = laguerre_l_gen(50, alpha, DualNumber::from_real(0.0f64));
+/// assert!((l.real() - binomial).abs() < 1e-12 * binomial);
+/// ```
+pub fn laguerre_l_gen<T>(n: u32, alpha: T, x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let mut prev = DualNumber::one();
+
+    if n == 0 {
+        return prev;
+    }
+
+    let mut curr = -x + (T::one() + alpha);
+
+    for k in 1..n {
+        let k = T::from(k).unwrap();
+        let next = (curr * (k + k + T::one() + alpha) - x * curr - prev * (k + alpha)) / (k + T::one());
+
+        prev = curr;
+        curr = next;
+    }
+
+    curr
+}
+
+/// Evaluates a polynomial at a dual point with Horner's method, fusing each step with `mul_add`.
+///
+/// Unlike `horner`, the coefficients are in ascending order, from the constant term to the
+/// highest degree, so `[2, -3, 1]` is `2 - 3x + x²`. An empty slice is the zero polynomial.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, polyval, polyder};
+/// let c = [2.0f64, -3.0, 1.0, 4.0];
+/// let p = polyval(&c, DualNumber::new(3.0, 1.0));
+///
+/// // 2 - 9 + 9 + 108, and -3 + 6 + 108
+/// assert_eq!(p.into_tuple(), (110.0, 111.0));
+/// assert_eq!(p.dual(), polyval(&polyder(&c), DualNumber::from_real(3.0)).real());
+///
+/// assert_eq!(polyval(&[], DualNumber::new(3.0f64, 1.0)).into_tuple(), (0.0, 0.0));
+/// assert_eq!(polyval(&[7.0], DualNumber::new(3.0f64, 1.0)).into_tuple(), (7.0, 0.0));
+///
+/// // Degree 50 against naive summation
+/// let c: Vec<f64> = (0..=50).map(|k| (-1f64).powi(k) / (k + 1) as f64).collect();
+/// let p = polyval(&c, DualNumber::new(0.9, 1.0));
+/// let naive: f64 = c.iter().enumerate().map(|(k, c)| c * 0.9f64.powi(k as i32)).sum();
+/// let naive_der: f64 = c.iter().enumerate().skip(1).map(|(k, c)| k as f64 * c * 0.9f64.powi(k as i32 - 1)).sum();
+///
+/// assert!((p.real() - naive).abs() < 1e-14);
+/// assert!((p.dual() - naive_der).abs() < 1e-13);
+/// ```
+pub fn polyval<T>(coeffs: &[T], x: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let (xr, xd) = x.into_tuple();
+
+    coeffs.iter().rev().fold(DualNumber::zero(), |acc, &c| {
+        let (r, d) = acc.into_tuple();
+
+        DualNumber::new(r.mul_add(xr, c), d.mul_add(xr, r * xd))
+    })
+}
+
+/// Like `polyval`, but the coefficients are dual numbers, so that their derivatives (for
+/// instance with respect to fit parameters) are carried into the result.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, polyval, polyval_dual_coeffs};
+/// // d/da (a + 2x + a x²) at x = 3 is 1 + x² = 10
+/// let a = DualNumber::new(0.5f64, 1.0);
+/// let c = [a, DualNumber::from_real(2.0), a];
+///
+/// assert_eq!(polyval_dual_coeffs(&c, DualNumber::from_real(3.0)).into_tuple(), (11.0, 10.0));
+///
+/// // Constant coefficients agree with polyval
+/// let c = [1.0f64, -2.0, 0.5];
+/// let lifted: Vec<_> = c.iter().map(|&c| DualNumber::from_real(c)).collect();
+/// let x = DualNumber::new(1.5f64, 1.0);
+/// assert_eq!(polyval_dual_coeffs(&lifted, x).into_tuple(), polyval(&c, x).into_tuple());
+/// ```
+pub fn polyval_dual_coeffs<T>(coeffs: &[DualNumber<T>], x: DualNumber<T>) -> DualNumber<T>
+    where T: Float + Signed + FloatConst {
+    let (xr, xd) = x.into_tuple();
+
+    coeffs.iter().rev().fold(DualNumber::zero(), |acc, c| {
+        let (r, d) = acc.into_tuple();
+
+        DualNumber::new(r.mul_add(xr, c.real()), d.mul_add(xr, r.mul_add(xd, c.dual())))
+    })
+}
+
+/// Coefficients of the derivative of a polynomial, both in ascending order as used by `polyval`.
+///
+/// Constant and empty polynomials give an empty vector, the zero polynomial.
+///
+/// ```rust
+/// # use dual_num::polyder;
+/// assert_eq!(polyder(&[2.0, -3.0, 1.0, 4.0]), vec![-3.0, 2.0, 12.0]);
+/// assert!(polyder(&[7.0]).is_empty());
+/// assert!(polyder::<f64>(&[]).is_empty());
+/// ```
+pub fn polyder<T>(coeffs: &[T]) -> Vec<T> where T: Float + Signed + FloatConst {
+    coeffs.iter().enumerate().skip(1).map(|(k, &c)| c * T::from(k).unwrap()).collect()
+}
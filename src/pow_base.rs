@@ -0,0 +1,120 @@
+//! Exponentiation of a fixed real base
+
+use super::{DualNumber, Float, FloatConst, One};
+
+use num_traits::Signed;
+
+/// A constant base for repeated exponentiation, `base^x`, with `ln(base)` computed once.
+///
+/// Raising many dual numbers to the same base through `powf` recomputes the logarithm needed
+/// by the derivative on every call; `PowBase` caches it instead.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, PowBase};
+/// let ten = PowBase::new(10.0f64);
+///
+/// for &v in &[-2.0f64, -0.5, 0.0, 0.3, 1.0, 4.5] {
+///     let x = DualNumber::new(v, 1.5);
+///     let naive = DualNumber::from_real(10.0).powf(x);
+///
+///     assert_eq!(ten.pow(x).into_tuple(), naive.into_tuple());
+/// }
+///
+/// assert_eq!(PowBase::new(0.0f64).pow(DualNumber::new(0.0, 1.0)).into_tuple(), (1.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PowBase<T> {
+    base: T,
+    ln_base: T,
+}
+
+impl<T> PowBase<T> where T: Float + Signed + FloatConst {
+    /// Creates a new base, computing its natural logarithm.
+    #[inline]
+    pub fn new(base: T) -> PowBase<T> {
+        PowBase { base, ln_base: base.ln() }
+    }
+
+    /// Returns the base
+    #[inline]
+    pub fn base(&self) -> T {
+        self.base
+    }
+
+    /// Returns the cached natural logarithm of the base
+    #[inline]
+    pub fn ln_base(&self) -> T {
+        self.ln_base
+    }
+
+    /// Computes `base^exp`, with derivative `base^exp ln(base) exp'`.
+    ///
+    /// This matches `DualNumber::from_real(base).powf(exp)`, including `0^0 = 1`.
+    pub fn pow(&self, exp: DualNumber<T>) -> DualNumber<T> {
+        if self.base.is_zero() && exp.real().is_zero() {
+            return DualNumber::one();
+        }
+
+        let real = self.base.powf(exp.real());
+
+        DualNumber::new(real, real * self.ln_base * exp.dual())
+    }
+}
+
+impl<T> DualNumber<T> where T: Float + Signed + FloatConst {
+    /// Computes `base^self` for a constant real base, with derivative `base^x ln(base) x'`.
+    ///
+    /// This is `PowBase::new(base).pow(self)`; use `PowBase` to reuse `ln(base)` across many calls.
+    ///
+    /// The exponential functions with the bases `e`, 2 and 10 agree with `exp_base` and with `powf`
+    /// of a constant base, in both parts, to within a few ulps. Since the constant `E` is rounded,
+    /// `E^x` additionally drifts from `exp(x)` by up to about `|x|` ulps:
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// use std::f64::consts::{E, LN_2, LN_10};
+    ///
+    /// let close = |a: DualNumber<f64>, b: DualNumber<f64>, x: f64| {
+    ///     let tol = |v: f64| (4.0 + x.abs()) * f64::EPSILON * v.abs();
+    ///     (a.real() - b.real()).abs() <= tol(b.real()) && (a.dual() - b.dual()).abs() <= tol(b.dual())
+    /// };
+    ///
+    /// for i in 0..200 {
+    ///     let x = DualNumber::new(i as f64 * 0.37 - 37.0, 1.0 - i as f64 * 0.01);
+    ///
+    ///     for &(base, ln_base, exp) in &[(E, 1.0, x.exp()), (2.0, LN_2, x.exp2()), (10.0, LN_10, x.exp10())] {
+    ///         assert!(close(exp, x.exp_base(base), x.real()));
+    ///         assert!(close(exp, DualNumber::from_real(base).powf(x), x.real()));
+    ///         assert!(close(exp, DualNumber::new(exp.real(), exp.real() * ln_base * x.dual()), 0.0));
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(DualNumber::new(3.0f64, 1.0).exp_base(2.0).into_tuple(), (8.0, 8.0 * LN_2));
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).exp_base(0.0).into_tuple(), (1.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn exp_base(self, base: T) -> Self {
+        PowBase::new(base).pow(self)
+    }
+}
+
+/// Computes `base^exponent` for a constant real base, differentiated through the exponent:
+/// `(base^e, base^e ln(base) e')`.
+///
+/// This is `exponent.exp_base(base)`, with the arguments in the order they are written.
+///
+/// ```rust
+/// # use dual_num::{exp_pow, DualNumber};
+/// use std::f64::consts::LN_2;
+///
+/// // d/dt 2^t at t = 3
+/// let y = exp_pow(2.0, DualNumber::new(3.0f64, 1.0));
+/// assert_eq!(y.into_tuple(), (8.0, 8.0 * LN_2));
+///
+/// let t = DualNumber::new(-0.75f64, 2.0);
+/// assert_eq!(exp_pow(5.0, t).into_tuple(), t.exp_base(5.0).into_tuple());
+/// ```
+#[inline]
+pub fn exp_pow<T>(base: T, exponent: DualNumber<T>) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    exponent.exp_base(base)
+}
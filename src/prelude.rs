@@ -0,0 +1,38 @@
+//! The recommended single import
+//!
+//! `use dual_num::prelude::*;` brings in the dual number types, the differentiation functions, the
+//! `num-traits` traits whose methods dual numbers implement, such as `Float` for `sqrt` and `exp`,
+//! and the `dual!` and `assert_dual_eq!` macros.
+//!
+//! ```rust
+//! use dual_num::prelude::*;
+//!
+//! // d/dx sin(x) e^x = e^x (sin(x) + cos(x))
+//! let d = differentiate(0.5f64, |x| x.sin() * x.exp());
+//! assert!((d - 0.5f64.exp() * (0.5f64.sin() + 0.5f64.cos())).abs() < 1e-15);
+//!
+//! let x: Dual64 = dual!(4.0 + 1.0 ε);
+//! assert_dual_eq!(x.sqrt(), 2.0, 0.25, 1e-15);
+//! assert_eq!(Dual64::PI().real(), std::f64::consts::PI);
+//! assert_eq!(try_differentiate(2.0f64, |x| x * x), Ok(4.0));
+//! assert!(Dual32::one() - Dual::zero() == 1.0);
+//! ```
+//!
+//! It does not shadow any name of the standard prelude:
+//!
+//! ```rust
+//! use dual_num::prelude::*;
+//!
+//! let r: Result<Option<Vec<String>>, Box<dyn std::error::Error>> = Ok(Some(vec![String::from("x")]));
+//! assert!(r.is_ok());
+//! let v: Vec<i32> = (1..4).map(|i| i * 2).collect();
+//! assert_eq!(v.iter().cloned().max(), Some(6));
+//! let zero: f64 = Default::default();
+//! assert_eq!(zero, 0.0);
+//! assert_eq!(2.0f64.max(3.0), 3.0);
+//! ```
+
+pub use super::{Dual, Dual32, Dual64, DualNumber, DualNumberF32, DualNumberF64, DualError, IntoDual};
+pub use super::{differentiate, differentiate_from, try_differentiate, seed_variables, seed_each};
+pub use super::{Float, FloatConst, Num, One, Zero};
+pub use {assert_dual_eq, dual};
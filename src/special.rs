@@ -0,0 +1,270 @@
+//! Functions built on the gamma function
+//!
+//! Only the logarithm of the gamma function and its derivative, the digamma function, are needed
+//! here, and both are evaluated on the real part.
+
+use super::{DualNumber, Float, FloatConst, One, Zero};
+
+use num_traits::Signed;
+
+/// Largest number of factors evaluated directly as a product before switching to the gamma form.
+const MAX_PRODUCT_FACTORS: usize = 64;
+
+/// Lanczos approximation coefficients for `g = 7`.
+const LANCZOS: [f64; 9] = [0.999_999_999_999_809_9, 676.520_368_121_885_1, -1_259.139_216_722_402_8,
+                           771.323_428_777_653_1, -176.615_029_162_140_6, 12.507_343_278_686_905,
+                           -0.138_571_095_265_720_12, 9.984_369_578_019_572e-6, 1.505_632_735_149_311_6e-7];
+
+/// Computes `sin(πx)` with the argument reduced to `[-1/2, 1/2]` first, so that it is
+/// accurate close to the integers.
+fn sin_pi<T>(x: T) -> T where T: Float + FloatConst {
+    let n = x.round();
+    let s = (T::PI() * (x - n)).sin();
+
+    if (n / (T::one() + T::one())).fract().is_zero() { s } else { -s }
+}
+
+/// Natural logarithm of the absolute value of the gamma function.
+fn ln_gamma<T>(x: T) -> T where T: Float + FloatConst {
+    let half = T::from(0.5).unwrap();
+
+    if x < half {
+        // Reflection formula, Γ(x) Γ(1 - x) = π / sin(πx)
+        return T::PI().ln() - sin_pi(x).abs().ln() - ln_gamma(T::one() - x);
+    }
+
+    let z = x - T::one();
+    let a = LANCZOS.iter().enumerate().skip(1)
+        .fold(T::from(LANCZOS[0]).unwrap(), |a, (i, &c)| a + T::from(c).unwrap() / (z + T::from(i).unwrap()));
+    let t = z + T::from(7.5).unwrap();
+
+    (T::PI() + T::PI()).sqrt().ln() + (z + half) * t.ln() - t + a.ln()
+}
+
+/// The digamma function, `ψ(x) = Γ'(x) / Γ(x)`.
+fn digamma<T>(x: T) -> T where T: Float + FloatConst {
+    if x < T::from(0.5).unwrap() {
+        // Reflection formula, ψ(1 - x) - ψ(x) = π / tan(πx)
+        let r = x - x.round();
+
+        return digamma(T::one() - x) - T::PI() / (T::PI() * r).tan();
+    }
+
+    // Shift the argument up with ψ(x) = ψ(x + 1) - 1/x, then use the asymptotic series
+    let mut x = x;
+    let mut shift = T::zero();
+
+    while x < T::from(10).unwrap() {
+        shift = shift - x.recip();
+        x = x + T::one();
+    }
+
+    let x2 = (x * x).recip();
+    let series = [1.0 / 12.0, -1.0 / 120.0, 1.0 / 252.0, -1.0 / 240.0, 1.0 / 132.0].iter().rev()
+        .fold(T::zero(), |acc, &c| (acc + T::from(c).unwrap()) * x2);
+
+    shift + x.ln() - (x + x).recip() - series
+}
+
+/// Sign of the gamma function, which alternates between its poles on the negative axis.
+fn gamma_sign<T>(x: T) -> T where T: Float {
+    if x > T::zero() || x == x.floor() {
+        return T::one();
+    }
+
+    if ((-x).ceil() / (T::one() + T::one())).fract().is_zero() { T::one() } else { -T::one() }
+}
+
+/// Number of leading factors of `(x)ₙ` to multiply out directly.
+///
+/// Small non-negative integer `n` is entirely a product. Otherwise, the factors are taken until
+/// `x + m >= 1`, which steps over the poles of the gamma function for negative `x`.
+fn leading_factors<T>(x: T, n: T) -> usize where T: Float {
+    let max = T::from(MAX_PRODUCT_FACTORS).unwrap();
+
+    if n == n.floor() && n >= T::zero() && n <= max {
+        n.to_usize().unwrap()
+    } else if x < T::one() && T::one() - x <= max {
+        (T::one() - x).ceil().to_usize().unwrap()
+    } else {
+        0
+    }
+}
+
+/// `ln|Γ(x + n)| - ln|Γ(x + m)|` on the real part of `x`, with derivative `ψ(x + n) - ψ(x + m)`.
+fn ln_gamma_ratio<T>(x: DualNumber<T>, n: T, m: T) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let (a, b) = (x.real() + n, x.real() + m);
+
+    DualNumber::new(ln_gamma(a) - ln_gamma(b), x.dual() * (digamma(a) - digamma(b)))
+}
+
+/// Logarithm of the absolute value of the rising factorial (Pochhammer symbol),
+/// `ln|(x)ₙ| = ln|Γ(x + n)| - ln|Γ(x)|`, with derivative `ψ(x + n) - ψ(x)`.
+///
+/// For integer `0 <= n <= 64` this is evaluated as the sum of `ln|x + k|`, which is exact
+/// at the poles of the gamma function: if one of the factors is zero, the value is `-∞`.
+/// Otherwise the gamma form is used, after first multiplying out enough factors to move
+/// a negative `x` past the poles.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, ln_pochhammer};
+/// // Integer n, as a product
+/// let p = ln_pochhammer(DualNumber::new(3.5f64, 1.0), 4.0);
+/// assert!((p.real() - (3.5f64 * 4.5 * 5.5 * 6.5).ln()).abs() < 1e-15);
+/// assert!((p.dual() - (1.0 / 3.5 + 1.0 / 4.5 + 1.0 / 5.5 + 1.0 / 6.5)).abs() < 1e-15);
+///
+/// // Large integer n goes through the gamma form, and agrees with the product
+/// let p = ln_pochhammer(DualNumber::new(0.75f64, 1.0), 100.0);
+/// let sum: f64 = (0..100).map(|k| (0.75 + k as f64).ln()).sum();
+/// let der: f64 = (0..100).map(|k| 1.0 / (0.75 + k as f64)).sum();
+/// assert!((p.real() - sum).abs() < 1e-12);
+/// assert!((p.dual() - der).abs() < 1e-13);
+///
+/// // (3/2)₅/₂ = Γ(4) / Γ(3/2), and ψ(4) - ψ(3/2) = 2 ln 2 - 1/6
+/// let p = ln_pochhammer(DualNumber::new(1.5f64, 1.0), 2.5);
+/// assert!((p.real() - (12.0 / std::f64::consts::PI.sqrt()).ln()).abs() < 1e-14);
+/// assert!((p.dual() - (2.0 * 2f64.ln() - 1.0 / 6.0)).abs() < 1e-14);
+///
+/// // Next to the pole of Γ(x) at x = -2, where (x)ₙ vanishes linearly
+/// let eps = 2f64.powi(-30);
+/// let p = ln_pochhammer(DualNumber::new(-2.0 + eps, 1.0), 5.5);
+/// let gamma_3_5 = 15.0 * std::f64::consts::PI.sqrt() / 8.0;
+/// assert!((p.real() - (2.0 * eps * gamma_3_5).ln()).abs() < 1e-8);
+/// assert!((p.dual() - (1.0 / eps - 1.5 + 46.0 / 15.0 - 2.0 * 2f64.ln())).abs() < 1e-5);
+///
+/// assert_eq!(ln_pochhammer(DualNumber::new(-2.0f64, 1.0), 3.0).real(), f64::NEG_INFINITY);
+/// ```
+pub fn ln_pochhammer<T>(x: DualNumber<T>, n: T) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let m = leading_factors(x.real(), n);
+
+    let product = (0..m).fold(DualNumber::zero(), |acc: DualNumber<T>, k| {
+        let f = x.real() + T::from(k).unwrap();
+
+        acc + DualNumber::new(f.abs().ln(), x.dual() / f)
+    });
+
+    let m = T::from(m).unwrap();
+
+    if m == n { product } else { product + ln_gamma_ratio(x, n, m) }
+}
+
+/// The rising factorial (Pochhammer symbol), `(x)ₙ = Γ(x + n) / Γ(x)`.
+///
+/// For integer `0 <= n <= 64` this is the product `x (x + 1) ... (x + n - 1)`, and otherwise it
+/// is computed from `ln_pochhammer`, so it overflows when `(x)ₙ` is not representable.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, pochhammer, ln_pochhammer};
+/// assert_eq!(pochhammer(DualNumber::new(2.0f64, 1.0), 3.0).into_tuple(), (24.0, 26.0));
+/// assert_eq!(pochhammer(DualNumber::new(2.0f64, 1.0), 0.0).into_tuple(), (1.0, 0.0));
+///
+/// // Finite at the poles of the gamma form, with the derivative of the product
+/// assert_eq!(pochhammer(DualNumber::new(-2.0f64, 1.0), 3.0).into_tuple(), (0.0, 2.0));
+///
+/// // Signs are kept for negative factors
+/// let x = DualNumber::new(-2.5f64, 1.0);
+/// let p = pochhammer(x, 1.25);
+/// let l = ln_pochhammer(x, 1.25);
+/// assert!(p.real() < 0.0);
+/// assert!((p.real().abs() - l.real().exp()).abs() < 1e-15);
+/// assert!((p.dual() - p.real() * l.dual()).abs() < 1e-14);
+///
+/// // (-1/2)₃/₂ = Γ(1) / Γ(-1/2) = -1 / (2√π)
+/// assert!((pochhammer(DualNumber::from_real(-0.5f64), 1.5).real() + 0.5 / std::f64::consts::PI.sqrt()).abs() < 1e-15);
+/// ```
+pub fn pochhammer<T>(x: DualNumber<T>, n: T) -> DualNumber<T> where T: Float + Signed + FloatConst {
+    let m = leading_factors(x.real(), n);
+    let product = (0..m).fold(DualNumber::one(), |acc: DualNumber<T>, k| acc * (x + T::from(k).unwrap()));
+
+    let m = T::from(m).unwrap();
+
+    if m == n {
+        return product;
+    }
+
+    let sign = gamma_sign(x.real() + n) * gamma_sign(x.real() + m);
+
+    product * ln_gamma_ratio(x, n, m).exp() * sign
+}
+
+/// Computes the binomial coefficient `C(n, k)` exactly by multiplication, for non-negative integers
+/// `k <= n` for which every intermediate product is exactly representable.
+fn binomial_exact<T>(n: T, k: T) -> Option<T> where T: Float {
+    if n < T::zero() || k < T::zero() || k > n || n != n.floor() || k != k.floor() {
+        return None;
+    }
+
+    let k = k.min(n - k);
+    let limit = T::epsilon().recip();
+    let mut c = T::one();
+    let mut i = T::one();
+
+    while i <= k {
+        let numerator = c * (n - k + i);
+
+        if numerator > limit {
+            return None;
+        }
+
+        c = numerator / i;
+        i = i + T::one();
+    }
+
+    Some(c)
+}
+
+/// Logarithm of the binomial coefficient, `ln C(n, k) = ln Γ(n + 1) - ln Γ(k + 1) - ln Γ(n - k + 1)`,
+/// differentiable in both `n` and `k`.
+///
+/// The derivatives are `ψ(n + 1) - ψ(n - k + 1)` with respect to `n` and `ψ(n - k + 1) - ψ(k + 1)`
+/// with respect to `k`. Either argument may be a dual number or a plain scalar.
+///
+/// The intended region is `n >= k >= 0`. Outside of it, this is the real-valued extension
+/// `ln|C(n, k)|` through the absolute value of the gamma function, and is infinite wherever
+/// one of the gamma functions has a pole. When `n` and `k` are non-negative integers and
+/// `C(n, k)` is exactly representable, the value is the logarithm of the exact integer.
+///
+/// ```rust
+/// # use dual_num::{DualNumber, Float, ln_binomial};
+/// let l = |n: f64, k: f64| ln_binomial(DualNumber::from_real(n), k).real();
+///
+/// assert_eq!(l(10.0, 3.0), 120f64.ln());
+/// assert_eq!(l(52.0, 5.0), 2_598_960f64.ln());
+/// assert_eq!(l(40.0, 20.0), 137_846_528_820f64.ln());
+/// assert_eq!(l(7.0, 0.0), 0.0);
+///
+/// // Pascal's rule, C(n, k) = C(n - 1, k - 1) + C(n - 1, k), for real arguments
+/// let c = |n: f64, k: f64| l(n, k).exp();
+/// assert!((c(7.3, 2.6) - c(6.3, 1.6) - c(6.3, 2.6)).abs() < 1e-12 * c(7.3, 2.6));
+///
+/// // Derivatives against finite differences
+/// let (n, k, h) = (7.3f64, 2.6f64, 1e-6);
+/// let dn = ln_binomial(DualNumber::new(n, 1.0), k).dual();
+/// let dk = ln_binomial(n, DualNumber::new(k, 1.0)).dual();
+/// assert!((dn - (l(n + h, k) - l(n - h, k)) / (2.0 * h)).abs() < 1e-8);
+/// assert!((dk - (l(n, k + h) - l(n, k - h)) / (2.0 * h)).abs() < 1e-8);
+///
+/// // Symmetry, C(n, k) = C(n, n - k)
+/// let n = DualNumber::new(7.3f64, 1.0);
+/// let a: DualNumber<f64> = ln_binomial(n, 2.6);
+/// let b: DualNumber<f64> = ln_binomial(n, n - 2.6);
+/// assert!((a.real() - b.real()).abs() < 1e-14);
+/// assert!((a.dual() - b.dual()).abs() < 1e-14);
+/// ```
+pub fn ln_binomial<T, N, K>(n: N, k: K) -> DualNumber<T>
+    where T: Float + Signed + FloatConst,
+          N: Into<DualNumber<T>>,
+          K: Into<DualNumber<T>> {
+    let (n, k) = (n.into(), k.into());
+    let one = T::one();
+
+    let (a, b, c) = (n.real() + one, k.real() + one, n.real() - k.real() + one);
+    let (da, db, dc) = (digamma(a), digamma(b), digamma(c));
+
+    let real = match binomial_exact(n.real(), k.real()) {
+        Some(exact) => exact.ln(),
+        None => ln_gamma(a) - ln_gamma(b) - ln_gamma(c),
+    };
+
+    DualNumber::new(real, n.dual() * (da - dc) + k.dual() * (dc - db))
+}
@@ -0,0 +1,307 @@
+//! Additional trigonometric functions not covered by `Float`
+
+use super::{DualNumber, Float, FloatConst};
+
+use num_traits::Signed;
+
+/// Computes the sine and cosine of an angle in degrees.
+///
+/// The argument is reduced in degrees to within 45 degrees of a multiple of 90 before
+/// converting to radians, so multiples of 30, 45 and 90 degrees give exact (correctly rounded) values.
+fn sin_cos_degrees<T: Float + FloatConst>(x: T) -> (T, T) {
+    if !x.is_finite() {
+        return (T::nan(), T::nan());
+    }
+
+    let (c30, c45, c90, c360) = (T::from(30).unwrap(), T::from(45).unwrap(),
+                                 T::from(90).unwrap(), T::from(360).unwrap());
+
+    let mut r = x % c360;
+
+    if r < T::zero() {
+        r = r + c360;
+    }
+
+    let quadrant = (r / c90).round();
+    let rem = r - quadrant * c90;
+
+    let (s, c) = if rem.abs() == c30 {
+        ((T::one() / (T::one() + T::one())).copysign(rem), rem.to_radians().cos())
+    } else if rem.abs() == c45 {
+        (T::FRAC_1_SQRT_2().copysign(rem), T::FRAC_1_SQRT_2())
+    } else {
+        rem.to_radians().sin_cos()
+    };
+
+    // Negating via subtraction keeps zeros positive, so that e.g. `cosd(90) == +0`
+    match quadrant.to_u8() {
+        Some(1) => (c, T::zero() - s),
+        Some(2) => (T::zero() - s, T::zero() - c),
+        Some(3) => (T::zero() - c, s),
+        _ => (s, c),
+    }
+}
+
+impl<T> DualNumber<T> where T: Float + Signed + FloatConst {
+    /// Sine of an angle in degrees.
+    ///
+    /// The dual part includes the `π/180` chain-rule factor. Multiples of 30 and 45 degrees are exact.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// let deg = |x: f64| DualNumber::new(x, 1.0);
+    ///
+    /// assert_eq!(deg(30.0).sind().real(), 0.5);
+    /// assert_eq!(deg(-30.0).sind().real(), -0.5);
+    /// assert_eq!(deg(45.0).sind().real(), f64::FRAC_1_SQRT_2());
+    /// assert_eq!(deg(90.0).sind().real(), 1.0);
+    /// assert_eq!(deg(180.0).sind().real(), 0.0);
+    /// assert_eq!(deg(150.0).sind().real(), 0.5);
+    /// assert_eq!(deg(720.0 + 270.0).sind().real(), -1.0);
+    ///
+    /// let x = deg(20.0);
+    /// let r = DualNumber::new(20f64.to_radians(), 1.0).sin();
+    /// assert!((x.sind().dual() - r.dual() * f64::PI() / 180.0).abs() < 1e-15);
+    /// ```
+    pub fn sind(self) -> Self {
+        let (s, c) = sin_cos_degrees(self.real());
+
+        DualNumber::new(s, self.dual().to_radians() * c)
+    }
+
+    /// Cosine of an angle in degrees.
+    ///
+    /// The dual part includes the `π/180` chain-rule factor. Multiples of 30 and 45 degrees are exact.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// let deg = |x: f64| DualNumber::new(x, 1.0);
+    ///
+    /// assert_eq!(deg(60.0).cosd().real(), 0.5);
+    /// assert_eq!(deg(90.0).cosd().real(), 0.0);
+    /// assert_eq!(deg(135.0).cosd().real(), -f64::FRAC_1_SQRT_2());
+    /// assert_eq!(deg(180.0).cosd().real(), -1.0);
+    ///
+    /// // d/dx cos(x°) = -sin(x°) π/180
+    /// assert_eq!(deg(90.0).cosd().dual(), -f64::PI() / 180.0);
+    /// ```
+    pub fn cosd(self) -> Self {
+        let (s, c) = sin_cos_degrees(self.real());
+
+        DualNumber::new(c, self.dual().to_radians() * (T::zero() - s))
+    }
+
+    /// Tangent of an angle in degrees.
+    ///
+    /// The dual part includes the `π/180` chain-rule factor. Multiples of 45 degrees are exact,
+    /// and odd multiples of 90 degrees give a signed infinity.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// let deg = |x: f64| DualNumber::new(x, 1.0);
+    ///
+    /// assert_eq!(deg(45.0).tand().real(), 1.0);
+    /// assert_eq!(deg(-45.0).tand().real(), -1.0);
+    /// assert_eq!(deg(180.0).tand().real(), 0.0);
+    /// assert_eq!(deg(90.0).tand().real(), f64::INFINITY);
+    ///
+    /// // d/dx tan(x°) = (1 + tan²(x°)) π/180
+    /// assert_eq!(deg(45.0).tand().dual(), 2.0 * f64::PI() / 180.0);
+    /// ```
+    pub fn tand(self) -> Self {
+        let (s, c) = sin_cos_degrees(self.real());
+        let t = s / c;
+
+        DualNumber::new(t, self.dual().to_radians() * (t * t + T::one()))
+    }
+
+    /// Inverse sine, in degrees.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.5f64, 1.0);
+    ///
+    /// assert!((x.asind().real() - 30.0).abs() < 1e-13);
+    /// assert!((x.asind().dual() - x.asin().dual().to_degrees()).abs() < 1e-13);
+    /// ```
+    pub fn asind(self) -> Self {
+        self.asin().to_degrees()
+    }
+
+    /// Inverse cosine, in degrees.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.5f64, 1.0);
+    ///
+    /// assert!((x.acosd().real() - 60.0).abs() < 1e-13);
+    /// assert!((x.acosd().dual() - x.acos().dual().to_degrees()).abs() < 1e-13);
+    /// ```
+    pub fn acosd(self) -> Self {
+        self.acos().to_degrees()
+    }
+
+    /// Inverse tangent, in degrees.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(1.0f64, 1.0);
+    ///
+    /// assert_eq!(x.atand().real(), 45.0);
+    /// assert!((x.atand().dual() - x.atan().dual().to_degrees()).abs() < 1e-13);
+    /// ```
+    pub fn atand(self) -> Self {
+        self.atan().to_degrees()
+    }
+
+    /// Four quadrant inverse tangent of `self / other`, in degrees.
+    ///
+    /// ```rust
+    /// # use dual_num::DualNumber;
+    /// let one = DualNumber::from_real(1.0f64);
+    ///
+    /// assert_eq!(one.atan2d(one).real(), 45.0);
+    /// assert_eq!(one.atan2d(-one).real(), 135.0);
+    /// assert_eq!((-one).atan2d(-one).real(), -135.0);
+    /// assert_eq!((-one).atan2d(one).real(), -45.0);
+    /// ```
+    pub fn atan2d(self, other: Self) -> Self {
+        self.atan2(other).to_degrees()
+    }
+
+    /// Secant, `1/cos(x)`, with derivative `sec(x) tan(x)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// let x = DualNumber::new(0.7f64, 1.0);
+    ///
+    /// assert!((x.sec().real() - x.cos().recip().real()).abs() < 1e-15);
+    /// assert!((x.sec().dual() - x.cos().recip().dual()).abs() < 1e-14);
+    ///
+    /// let h = 1e-6;
+    /// let fd = (1.0 / (0.7f64 + h).cos() - 1.0 / (0.7f64 - h).cos()) / (2.0 * h);
+    /// assert!((x.sec().dual() - fd).abs() < 1e-8);
+    ///
+    /// // Near the pole at π/2, both parts blow up
+    /// let p = DualNumber::new(f64::FRAC_PI_2() - 1e-10, 1.0).sec();
+    /// assert!(p.real() > 1e9 && p.dual() > 1e19);
+    /// ```
+    pub fn sec(self) -> Self {
+        let (s, c) = self.real().sin_cos();
+        let sec = c.recip();
+
+        DualNumber::new(sec, self.dual() * sec * s / c)
+    }
+
+    /// Cosecant, `1/sin(x)`, with derivative `-csc(x) cot(x)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.7f64, 1.0);
+    ///
+    /// assert!((x.csc().real() - x.sin().recip().real()).abs() < 1e-15);
+    /// assert!((x.csc().dual() - x.sin().recip().dual()).abs() < 1e-14);
+    ///
+    /// let h = 1e-6;
+    /// let fd = (1.0 / (0.7f64 + h).sin() - 1.0 / (0.7f64 - h).sin()) / (2.0 * h);
+    /// assert!((x.csc().dual() - fd).abs() < 1e-8);
+    ///
+    /// // Pole at zero
+    /// let p = DualNumber::new(0.0f64, 1.0).csc();
+    /// assert_eq!(p.real(), f64::INFINITY);
+    /// assert_eq!(p.dual(), f64::NEG_INFINITY);
+    /// ```
+    pub fn csc(self) -> Self {
+        let (s, c) = self.real().sin_cos();
+        let csc = s.recip();
+
+        DualNumber::new(csc, -self.dual() * csc * c / s)
+    }
+
+    /// Cotangent, `cos(x)/sin(x)`, with derivative `-csc²(x)`.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(0.7f64, 1.0);
+    ///
+    /// assert!((x.cot().real() - x.tan().recip().real()).abs() < 1e-15);
+    /// assert!((x.cot().dual() + x.csc().real().powi(2)).abs() < 1e-14);
+    ///
+    /// // Round trip through acot on (0, π)
+    /// for &v in &[0.1f64, 0.7, 1.5, 2.5, 3.0] {
+    ///     let y = DualNumber::new(v, 1.0).cot().acot();
+    ///
+    ///     assert!((y.real() - v).abs() < 1e-14);
+    ///     assert!((y.dual() - 1.0).abs() < 1e-13);
+    /// }
+    /// ```
+    pub fn cot(self) -> Self {
+        let (s, c) = self.real().sin_cos();
+        let csc = s.recip();
+
+        DualNumber::new(c * csc, -self.dual() * csc * csc)
+    }
+
+    /// Inverse secant, `acos(1/x)`, defined for `|x| >= 1` with values in `[0, π]`.
+    ///
+    /// The derivative is `1/(|x| sqrt(x² - 1))`, which is infinite at `|x| = 1`.
+    /// Inside of `(-1, 1)` both parts are NaN.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(2.0f64, 1.0);
+    ///
+    /// assert!((x.asec().real() - x.recip().acos().real()).abs() < 1e-15);
+    /// assert!((x.asec().dual() - 1.0 / (2.0 * 3f64.sqrt())).abs() < 1e-15);
+    ///
+    /// assert_eq!(DualNumber::new(1.0f64, 1.0).asec().into_tuple(), (0.0, f64::INFINITY));
+    /// assert!(DualNumber::new(0.5f64, 1.0).asec().real().is_nan());
+    /// ```
+    pub fn asec(self) -> Self {
+        let x = self.real();
+
+        DualNumber::new(x.recip().acos(),
+                        self.dual() / (x.abs() * ((x - T::one()) * (x + T::one())).sqrt()))
+    }
+
+    /// Inverse cosecant, `asin(1/x)`, defined for `|x| >= 1` with values in `[-π/2, π/2]`.
+    ///
+    /// The derivative is `-1/(|x| sqrt(x² - 1))`, which is infinite at `|x| = 1`.
+    /// Inside of `(-1, 1)` both parts are NaN.
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float};
+    /// let x = DualNumber::new(-2.0f64, 1.0);
+    ///
+    /// assert!((x.acsc().real() - x.recip().asin().real()).abs() < 1e-15);
+    /// assert!((x.acsc().dual() + 1.0 / (2.0 * 3f64.sqrt())).abs() < 1e-15);
+    ///
+    /// assert!(DualNumber::new(0.5f64, 1.0).acsc().real().is_nan());
+    /// ```
+    pub fn acsc(self) -> Self {
+        let x = self.real();
+
+        DualNumber::new(x.recip().asin(),
+                        -self.dual() / (x.abs() * ((x - T::one()) * (x + T::one())).sqrt()))
+    }
+
+    /// Inverse cotangent, with derivative `-1/(1 + x²)`.
+    ///
+    /// This uses the convention `acot(x) = π/2 - atan(x)`, with values in `(0, π)`,
+    /// which is continuous at zero (unlike `atan(1/x)`).
+    ///
+    /// ```rust
+    /// # use dual_num::{DualNumber, Float, FloatConst};
+    /// assert_eq!(DualNumber::new(0.0f64, 1.0).acot().into_tuple(), (f64::FRAC_PI_2(), -1.0));
+    /// assert!((DualNumber::new(1.0f64, 1.0).acot().real() - f64::FRAC_PI_4()).abs() < 1e-15);
+    ///
+    /// let x = DualNumber::new(-1.0f64, 1.0).acot();
+    /// assert!((x.real() - 3.0 * f64::FRAC_PI_4()).abs() < 1e-15);
+    /// assert_eq!(x.dual(), -0.5);
+    /// ```
+    pub fn acot(self) -> Self {
+        let x = self.real();
+
+        DualNumber::new(T::FRAC_PI_2() - x.atan(), -self.dual() / (x * x + T::one()))
+    }
+}